@@ -0,0 +1,162 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::error::Error;
+use crate::solution::Answer;
+use crate::util;
+
+/// Name of the file in every day directory that holds the expected answers of its inputs.
+pub const ANSWERS_FILE_NAME: &str = "answers.txt";
+
+/// What an expected answer belongs to: one of the two parts, or a named value of the day (see `Solution::values`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnswerKey {
+    Part(u32),
+    Value(String),
+}
+
+impl fmt::Display for AnswerKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            AnswerKey::Part(part) => write!(f, "part {}", part),
+            AnswerKey::Value(name) => write!(f, "value {}", name),
+        };
+    }
+}
+
+/// An expected answer of one named input, e.g. `input part1 1532` in `data/day1/answers.txt`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectedAnswer {
+    pub input: String,
+    pub key: AnswerKey,
+    pub answer: String,
+}
+
+/// Parses an answers file. Every line has the form `<input name> <key> <answer>`, where the key is `part1`, `part2`
+/// or the name of a value (e.g. `gamma`). Empty lines and lines starting with `#` are ignored. Line breaks of
+/// multi-line answers are written as `\n`.
+pub fn parse_answers(contents: &str) -> Result<Vec<ExpectedAnswer>, Error> {
+    let mut answers: Vec<ExpectedAnswer> = vec![];
+
+    for (line_number, line) in util::numbered_lines(contents) {
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let elements = util::split_with_columns(line, " ");
+        if elements.len() != 3 {
+            return Err(Error::parse(line_number, 1, format!("expected '<input name> <key> <answer>', found '{}'", line)));
+        }
+
+        let (column, key) = elements[1];
+        let key = match key {
+            "part1" => AnswerKey::Part(1),
+            "part2" => AnswerKey::Part(2),
+            _ if key.starts_with("part") => return Err(Error::parse(line_number, column, format!("expected 'part1' or 'part2', found '{}'", key))),
+            _ if key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => AnswerKey::Value(key.to_string()),
+            _ => return Err(Error::parse(line_number, column, format!("expected 'part1', 'part2' or a value name, found '{}'", key))),
+        };
+
+        answers.push(ExpectedAnswer { input: elements[0].1.to_string(), key, answer: unescape_answer(elements[2].1) });
+    }
+
+    return Ok(answers);
+}
+
+/// Reads the answers file of a day directory. A directory without answers file has no expected answers.
+pub fn read_answers(day_directory: &Path) -> Result<Vec<ExpectedAnswer>, Error> {
+    let path = day_directory.join(ANSWERS_FILE_NAME);
+    if !path.is_file() {
+        return Ok(vec![]);
+    }
+
+    let path_name = path.display().to_string();
+    let contents = fs::read_to_string(&path).map_err(|source| Error::Io { path: path_name.to_string(), source })?;
+
+    return parse_answers(&contents).map_err(|error| error.with_file(&path_name));
+}
+
+/// Solves every input that has an entry in the answers file of the day in `data_directory` (e.g. `data/day3`)
+/// and returns how many answers were checked, together with a message for every wrong answer.
+pub fn check_answers(data_directory: &Path, day: u32) -> Result<(usize, Vec<String>), Error> {
+    let expected_answers = read_answers(&data_directory.join(format!("day{}", day)))?;
+    let mut failures: Vec<String> = vec![];
+
+    for expected in &expected_answers {
+        let input_name = data_directory.join(util::input_file_name(day, &expected.input)).display().to_string();
+        let answer = util::read_input(&input_name).and_then(|input| solve_key(day, &expected.key, &input));
+
+        match answer {
+            Ok(answer) if answer.to_string() == expected.answer => {},
+            Ok(answer) => failures.push(format!("{} {}: expected {:?}, got {:?}", input_name, expected.key, expected.answer, answer.to_string())),
+            Err(error) => failures.push(format!("{} {}: {}", input_name, expected.key, error.with_file(&input_name))),
+        }
+    }
+
+    return Ok((expected_answers.len(), failures));
+}
+
+fn solve_key(day: u32, key: &AnswerKey, input: &str) -> Result<Answer, Error> {
+    let not_solved = || Error::input(format!("day {} is not solved", day));
+
+    return match key {
+        AnswerKey::Part(part) => crate::solve(day, *part, input).ok_or_else(not_solved)?,
+        AnswerKey::Value(name) => {
+            let values = crate::solve_values(day, input).ok_or_else(not_solved)??;
+            let value = values.into_iter().find(|(value_name, _)| value_name == name);
+            value.map(|(_, answer)| answer).ok_or_else(|| Error::input(format!("day {} has no value '{}'", day, name)))
+        },
+    };
+}
+
+pub fn escape_answer(answer: &str) -> String {
+    return answer.replace('\\', "\\\\").replace('\n', "\\n");
+}
+
+pub fn unescape_answer(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            },
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            },
+            _ => unescaped.push(c),
+        }
+    }
+
+    return unescaped;
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers = parse_answers("# comment\ninput part1 1532\n\nalice part2 #..#\\n####\n").unwrap();
+
+    assert_eq!(answers, vec![
+        ExpectedAnswer { input: String::from("input"), key: AnswerKey::Part(1), answer: String::from("1532") },
+        ExpectedAnswer { input: String::from("alice"), key: AnswerKey::Part(2), answer: String::from("#..#\n####") },
+    ]);
+}
+
+#[test]
+fn test_parse_answers_unknown_part() {
+    let error = parse_answers("input part3 1").unwrap_err();
+
+    assert_eq!(error.to_string(), "line 1, column 7: expected 'part1' or 'part2', found 'part3'");
+}
+
+#[test]
+fn test_parse_answers_values() {
+    let answers = parse_answers("example gamma 22\nexample part1 198").unwrap();
+
+    assert_eq!(answers[0].key, AnswerKey::Value(String::from("gamma")));
+    assert_eq!(answers[1].key, AnswerKey::Part(1));
+    assert!(parse_answers("example gamma-rate 22").is_err());
+}
@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::util;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Statistics {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) { (sorted[middle - 1] + sorted[middle]) / 2 } else { sorted[middle] };
+
+        return Statistics { min: sorted[0], median, max: sorted[sorted.len() - 1] };
+    }
+}
+
+/// Timing statistics of one step ("parse", "part1" or "part2") of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub day: u32,
+    pub step: String,
+    pub statistics: Statistics,
+}
+
+/// Solves both parts of the day `runs` times and measures parsing and each part separately.
+pub fn benchmark(day: u32, input: &str, runs: usize) -> Result<Vec<Measurement>, Error> {
+    let runner = crate::get_runner(day).ok_or_else(|| Error::input(format!("day {} is not solved", day)))?;
+
+    let mut samples: Vec<Vec<Duration>> = vec![vec![]; 3];
+    for _ in 0..runs.max(1) {
+        let result = runner(input, &[1, 2], false)?;
+        samples[0].push(result.parse_elapsed);
+        for part_result in &result.parts {
+            samples[part_result.part as usize].push(part_result.elapsed);
+        }
+    }
+
+    return Ok(["parse", "part1", "part2"].iter().zip(samples).map(|(step, samples)| {
+        Measurement { day, step: step.to_string(), statistics: Statistics::from_samples(&samples) }
+    }).collect());
+}
+
+/// Writes the measurements in the format read by `parse_baseline`, one `<day> <step> <min> <median> <max>` line
+/// per measurement with all durations in nanoseconds.
+pub fn format_baseline(measurements: &[Measurement]) -> String {
+    let mut output = String::from("# day step min_ns median_ns max_ns\n");
+
+    for measurement in measurements {
+        let statistics = &measurement.statistics;
+        output += &format!("{} {} {} {} {}\n", measurement.day, measurement.step, statistics.min.as_nanos(), statistics.median.as_nanos(), statistics.max.as_nanos());
+    }
+
+    return output;
+}
+
+pub fn parse_baseline(contents: &str) -> Result<Vec<Measurement>, Error> {
+    let mut measurements: Vec<Measurement> = vec![];
+
+    for (line_number, line) in util::numbered_lines(contents) {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let elements = util::split_with_columns(line, " ");
+        if elements.len() != 5 {
+            return Err(Error::parse(line_number, 1, format!("expected '<day> <step> <min_ns> <median_ns> <max_ns>', found '{}'", line)));
+        }
+
+        let day = util::parse_value::<u32>(elements[0].1, line_number, elements[0].0)?;
+        let mut durations: Vec<Duration> = vec![];
+        for (column, value) in &elements[2..] {
+            durations.push(Duration::from_nanos(util::parse_value::<u64>(value, line_number, *column)?));
+        }
+
+        let statistics = Statistics { min: durations[0], median: durations[1], max: durations[2] };
+        measurements.push(Measurement { day, step: elements[1].1.to_string(), statistics });
+    }
+
+    return Ok(measurements);
+}
+
+/// Returns the change of the median compared to the baseline in percent, positive values are slower.
+pub fn compare_to_baseline(measurement: &Measurement, baseline: &[Measurement]) -> Option<f64> {
+    let baseline_measurement = baseline.iter().find(|x| x.day == measurement.day && x.step == measurement.step)?;
+    let baseline_median = baseline_measurement.statistics.median.as_secs_f64();
+    if baseline_median == 0.0 {
+        return None;
+    }
+
+    return Some((measurement.statistics.median.as_secs_f64() - baseline_median) / baseline_median * 100.0);
+}
+
+/// Formats a duration with a unit that keeps the number short, e.g. "12.3 µs" or "4.56 ms".
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    return if nanos < 1e3 {
+        format!("{} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.3} s", nanos / 1e9)
+    };
+}
+
+#[test]
+fn test_statistics_from_samples() {
+    let samples: Vec<Duration> = [5, 1, 4, 2].iter().map(|x| Duration::from_millis(*x)).collect();
+    let statistics = Statistics::from_samples(&samples);
+
+    assert_eq!(statistics, Statistics { min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(5) });
+}
+
+#[test]
+fn test_baseline_round_trip() {
+    let statistics = Statistics { min: Duration::from_nanos(10), median: Duration::from_nanos(20), max: Duration::from_nanos(40) };
+    let measurements = vec![Measurement { day: 14, step: String::from("part2"), statistics }];
+    let baseline = parse_baseline(&format_baseline(&measurements)).unwrap();
+
+    assert_eq!(baseline, measurements);
+
+    let slower = Measurement { day: 14, step: String::from("part2"), statistics: Statistics { median: Duration::from_nanos(30), ..statistics } };
+    assert_eq!(compare_to_baseline(&slower, &baseline).map(f64::round), Some(50.0));
+}
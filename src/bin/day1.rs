@@ -0,0 +1,6 @@
+use aoc2021::day1::Day1;
+
+fn main()
+{
+    aoc2021::run_binary::<Day1>("day1/input.txt");
+}
@@ -0,0 +1,6 @@
+use aoc2021::day10::Day10;
+
+fn main()
+{
+    aoc2021::run_binary::<Day10>("day10/input.txt");
+}
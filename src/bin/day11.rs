@@ -0,0 +1,6 @@
+use aoc2021::day11::Day11;
+
+fn main()
+{
+    aoc2021::run_binary::<Day11>("day11/input.txt");
+}
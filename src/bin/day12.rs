@@ -0,0 +1,6 @@
+use aoc2021::day12::Day12;
+
+fn main()
+{
+    aoc2021::run_binary::<Day12>("day12/input.txt");
+}
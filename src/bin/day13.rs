@@ -0,0 +1,5 @@
+use aoc2021::day13::Day13;
+
+fn main() {
+    aoc2021::run_binary::<Day13>("day13/input.txt");
+}
@@ -0,0 +1,5 @@
+use aoc2021::day14::Day14;
+
+fn main() {
+    aoc2021::run_binary::<Day14>("day14/input.txt");
+}
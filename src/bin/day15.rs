@@ -0,0 +1,5 @@
+use aoc2021::day15::Day15;
+
+fn main() {
+    aoc2021::run_binary::<Day15>("day15/input.txt");
+}
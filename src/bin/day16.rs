@@ -0,0 +1,5 @@
+use aoc2021::day16::Day16;
+
+fn main() {
+    aoc2021::run_binary::<Day16>("day16/input.txt");
+}
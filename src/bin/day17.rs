@@ -0,0 +1,5 @@
+use aoc2021::day17::Day17;
+
+fn main() {
+    aoc2021::run_binary::<Day17>("day17/input.txt");
+}
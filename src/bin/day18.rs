@@ -0,0 +1,5 @@
+use aoc2021::day18::Day18;
+
+fn main() {
+    aoc2021::run_binary::<Day18>("day18/input.txt");
+}
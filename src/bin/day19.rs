@@ -0,0 +1,5 @@
+use aoc2021::day19::Day19;
+
+fn main() {
+    aoc2021::run_binary::<Day19>("day19/input.txt");
+}
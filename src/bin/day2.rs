@@ -0,0 +1,6 @@
+use aoc2021::day2::Day2;
+
+fn main()
+{
+    aoc2021::run_binary::<Day2>("day2/input.txt");
+}
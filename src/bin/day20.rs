@@ -0,0 +1,5 @@
+use aoc2021::day20::Day20;
+
+fn main() {
+    aoc2021::run_binary::<Day20>("day20/input.txt");
+}
@@ -0,0 +1,5 @@
+use aoc2021::day21::Day21;
+
+fn main() {
+    aoc2021::run_binary::<Day21>("day21/input.txt");
+}
@@ -0,0 +1,5 @@
+use aoc2021::day22::Day22;
+
+fn main() {
+    aoc2021::run_binary::<Day22>("day22/input.txt");
+}
@@ -0,0 +1,5 @@
+use aoc2021::day23::Day23;
+
+fn main() {
+    aoc2021::run_binary::<Day23>("day23/input.txt");
+}
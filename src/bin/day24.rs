@@ -0,0 +1,5 @@
+use aoc2021::day24::Day24;
+
+fn main() {
+    aoc2021::run_binary::<Day24>("day24/input.txt");
+}
@@ -0,0 +1,5 @@
+use aoc2021::day25::Day25;
+
+fn main() {
+    aoc2021::run_binary::<Day25>("day25/input.txt");
+}
@@ -0,0 +1,6 @@
+use aoc2021::day3::Day3;
+
+fn main()
+{
+    aoc2021::run_binary::<Day3>("day3/input.txt");
+}
@@ -0,0 +1,6 @@
+use aoc2021::day4::Day4;
+
+fn main()
+{
+    aoc2021::run_binary::<Day4>("day4/input.txt");
+}
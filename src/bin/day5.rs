@@ -0,0 +1,6 @@
+use aoc2021::day5::Day5;
+
+fn main()
+{
+    aoc2021::run_binary::<Day5>("day5/input.txt");
+}
@@ -0,0 +1,6 @@
+use aoc2021::day6::Day6;
+
+fn main()
+{
+    aoc2021::run_binary::<Day6>("day6/input.txt");
+}
@@ -0,0 +1,6 @@
+use aoc2021::day7::Day7;
+
+fn main()
+{
+    aoc2021::run_binary::<Day7>("day7/input.txt");
+}
@@ -0,0 +1,6 @@
+use aoc2021::day8::Day8;
+
+fn main()
+{
+    aoc2021::run_binary::<Day8>("day8/input.txt");
+}
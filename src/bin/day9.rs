@@ -0,0 +1,6 @@
+use aoc2021::day9::Day9;
+
+fn main()
+{
+    aoc2021::run_binary::<Day9>("day9/input.txt");
+}
@@ -1,49 +1,41 @@
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main()
-{
-    let input = util::read_input_file("day1.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let measurements = util::parse_strings_as_ints(&lines);
-
-    let larger_than_previous = count_measurements_larger_than_previous(&measurements);
-    println!("Part 1: Solution={}", larger_than_previous);
+pub mod stats;
+pub mod stream;
 
-    let triples_larger_than_previous = count_measurements_triples_larger_than_previous(&measurements);
-    println!("Part 2: Solution={}", triples_larger_than_previous);
-}
+pub struct Day1;
 
-fn count_measurements_larger_than_previous(measurements: &Vec<i32>) -> i32
+impl Solution for Day1
 {
-    let mut larger_than_previous: i32 = 0;
+    type Input = Vec<i32>;
 
-    for i in 1..measurements.len()
+    fn parse(input: &str) -> Result<Vec<i32>, Error>
     {
-        if measurements[i] > measurements[i-1]
-        {
-            larger_than_previous += 1;
-        }
+        return util::parse_lines::<i32>(input);
     }
 
-    return larger_than_previous;
-}
-
-fn count_measurements_triples_larger_than_previous(measurements: &Vec<i32>) -> i32
-{
-    let mut larger_than_previous: i32 = 0;
-    let mut previous_triple_sum: i32 = -1;
+    fn part1(measurements: &Vec<i32>) -> Result<Answer, Error>
+    {
+        return Ok(count_window_increases(measurements, 1).into());
+    }
 
-    for i in 2..measurements.len()
+    fn part2(measurements: &Vec<i32>) -> Result<Answer, Error>
     {
-        let triple_sum = measurements[i] + measurements[i-1] + measurements[i-2];
-        if previous_triple_sum > 0 && triple_sum > previous_triple_sum
-        {
-            larger_than_previous += 1;
-        }
-        previous_triple_sum = triple_sum;
+        return Ok(count_window_increases(measurements, 3).into());
     }
+}
 
-    return larger_than_previous;
+/// Counts how often the sum of a window of `window_size` consecutive measurements is larger than the sum of the
+/// window before it. Consecutive windows share all but their first and last measurement, so comparing those two is
+/// the same as comparing the sums, works for any ordered type and cannot overflow.
+pub fn count_window_increases<T: PartialOrd>(measurements: &[T], window_size: usize) -> usize
+{
+    return measurements.iter()
+        .zip(measurements.iter().skip(window_size))
+        .filter(|(first, last)| last > first)
+        .count();
 }
 
 #[test]
@@ -51,7 +43,7 @@ fn test_day1_part1_example()
 {
     let measurements = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
 
-    let larger_than_previous = count_measurements_larger_than_previous(&measurements);
+    let larger_than_previous = count_window_increases(&measurements, 1);
 
     assert_eq!(larger_than_previous, 7);
 }
@@ -59,11 +51,10 @@ fn test_day1_part1_example()
 #[test]
 fn test_day1_part1_solution()
 {
-    let input = util::read_input_file("day1.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let measurements = util::parse_strings_as_ints(&lines);
+    let input = util::read_input_file("day1/input.txt").unwrap();
+    let measurements = util::parse_lines::<i32>(&input).unwrap();
 
-    let larger_than_previous = count_measurements_larger_than_previous(&measurements);
+    let larger_than_previous = count_window_increases(&measurements, 1);
 
     assert_eq!(larger_than_previous, 1532);
 }
@@ -71,9 +62,9 @@ fn test_day1_part1_solution()
 #[test]
 fn test_day1_part2_example()
 {
-    let measurements = vec![607, 618, 618, 617, 647, 716, 769, 792];
+    let measurements = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
 
-    let larger_than_previous = count_measurements_triples_larger_than_previous(&measurements);
+    let larger_than_previous = count_window_increases(&measurements, 3);
 
     assert_eq!(larger_than_previous, 5);
 }
@@ -81,11 +72,27 @@ fn test_day1_part2_example()
 #[test]
 fn test_day1_part2_solution()
 {
-    let input = util::read_input_file("day1.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let measurements = util::parse_strings_as_ints(&lines);
+    let input = util::read_input_file("day1/input.txt").unwrap();
+    let measurements = util::parse_lines::<i32>(&input).unwrap();
 
-    let larger_than_previous = count_measurements_triples_larger_than_previous(&measurements);
+    let larger_than_previous = count_window_increases(&measurements, 3);
 
     assert_eq!(larger_than_previous, 1571);
 }
+
+#[test]
+fn test_day1_window_sums()
+{
+    let measurements: Vec<i64> = vec![-5, 0, 0, -3, 2, 0, -7, 4, 4, -1];
+
+    for window_size in 0..12
+    {
+        let sums: Vec<i64> = measurements.windows(window_size.max(1)).map(|window| if window_size == 0 { 0 } else { window.iter().sum() }).collect();
+        let expected = sums.windows(2).filter(|pair| pair[1] > pair[0]).count();
+
+        assert_eq!(count_window_increases(&measurements, window_size), expected, "window size {}", window_size);
+    }
+
+    assert_eq!(count_window_increases(&[0u8, 0, 1, 1, 0, 2], 3), 2);
+    assert_eq!(count_window_increases::<i32>(&[], 3), 0);
+}
@@ -0,0 +1,211 @@
+use crate::error::Error;
+#[cfg(test)]
+use crate::util;
+
+/// How the moving averages and spikes of a depth report are computed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatsOptions {
+    /// Number of measurements of every moving average.
+    pub average_window: usize,
+    /// Number of previous measurements whose mean a measurement is compared with.
+    pub spike_window: usize,
+    /// A measurement is a spike if it differs from that mean by more than this.
+    pub spike_threshold: f64,
+}
+
+impl Default for StatsOptions {
+    fn default() -> StatsOptions {
+        return StatsOptions { average_window: 3, spike_window: 10, spike_threshold: 50.0 };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spike {
+    /// Index of the measurement, starting at 0.
+    pub index: usize,
+    pub depth: i32,
+    pub rolling_mean: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepthReport {
+    pub options: StatsOptions,
+    pub depths: Vec<i32>,
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+    /// The average of every window of `average_window` consecutive measurements, the first one ends at index
+    /// `average_window - 1`.
+    pub moving_averages: Vec<f64>,
+    /// The mean of the `spike_window` measurements before every measurement, None for the first ones.
+    pub rolling_means: Vec<Option<f64>>,
+    /// Index of the first measurement and number of measurements of the longest strictly increasing run.
+    pub longest_increasing_run: (usize, usize),
+    /// Index of the measurement after the largest drop and the size of the drop, None if the depth never decreases.
+    pub largest_drop: Option<(usize, i64)>,
+    pub spikes: Vec<Spike>,
+}
+
+pub fn depth_report(depths: &Vec<i32>, options: &StatsOptions) -> Result<DepthReport, Error> {
+    if depths.is_empty() {
+        return Err(Error::input("there are no measurements"));
+    }
+    if options.average_window == 0 || options.spike_window == 0 {
+        return Err(Error::input("the windows need at least one measurement"));
+    }
+
+    let min = *depths.iter().min().unwrap();
+    let max = *depths.iter().max().unwrap();
+    let mean = depths.iter().map(|depth| *depth as i64).sum::<i64>() as f64 / depths.len() as f64;
+
+    let moving_averages = window_sums(depths, options.average_window).iter().map(|sum| *sum as f64 / options.average_window as f64).collect();
+
+    let mut rolling_means: Vec<Option<f64>> = vec![None; options.spike_window.min(depths.len())];
+    rolling_means.extend(window_sums(depths, options.spike_window).iter().take(depths.len().saturating_sub(options.spike_window)).map(|sum| Some(*sum as f64 / options.spike_window as f64)));
+
+    let spikes = rolling_means.iter().enumerate()
+        .filter_map(|(index, rolling_mean)| rolling_mean.map(|rolling_mean| Spike { index, depth: depths[index], rolling_mean }))
+        .filter(|spike| (spike.depth as f64 - spike.rolling_mean).abs() > options.spike_threshold)
+        .collect();
+
+    let mut longest_increasing_run = (0, 1);
+    let mut run_start = 0;
+    let mut largest_drop: Option<(usize, i64)> = None;
+
+    for i in 1..depths.len() {
+        if depths[i] <= depths[i - 1] {
+            run_start = i;
+        }
+        if i + 1 - run_start > longest_increasing_run.1 {
+            longest_increasing_run = (run_start, i + 1 - run_start);
+        }
+
+        let drop = depths[i - 1] as i64 - depths[i] as i64;
+        if drop > 0 && largest_drop.is_none_or(|(_, largest)| drop > largest) {
+            largest_drop = Some((i, drop));
+        }
+    }
+
+    return Ok(DepthReport { options: options.clone(), depths: depths.clone(), min, max, mean, moving_averages, rolling_means, longest_increasing_run, largest_drop, spikes });
+}
+
+// The sums of all windows of the given size, in O(n)
+fn window_sums(depths: &Vec<i32>, window_size: usize) -> Vec<i64> {
+    if depths.len() < window_size {
+        return vec![];
+    }
+
+    let mut sum: i64 = depths[..window_size].iter().map(|depth| *depth as i64).sum();
+    let mut sums = vec![sum];
+
+    for i in window_size..depths.len() {
+        sum += depths[i] as i64 - depths[i - window_size] as i64;
+        sums.push(sum);
+    }
+
+    return sums;
+}
+
+impl DepthReport {
+    /// A summary with one statistic per line and the spikes, measurements are numbered from 1.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("measurements: {}\nmin: {}\nmax: {}\nmean: {:.2}\n", self.depths.len(), self.min, self.max, self.mean);
+
+        let averages = &self.moving_averages;
+        if averages.is_empty() {
+            text += &format!("moving average of {}: not enough measurements\n", self.options.average_window);
+        } else {
+            let lowest = averages.iter().cloned().fold(f64::INFINITY, f64::min);
+            let highest = averages.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            text += &format!("moving average of {}: first {:.2}, last {:.2}, min {:.2}, max {:.2}\n", self.options.average_window, averages[0], averages[averages.len() - 1], lowest, highest);
+        }
+
+        let (start, length) = self.longest_increasing_run;
+        text += &format!("longest increasing run: {} measurements from measurement {}\n", length, start + 1);
+
+        text += &match self.largest_drop {
+            Some((index, drop)) => format!("largest drop: {} at measurement {}\n", drop, index + 1),
+            None => String::from("largest drop: none\n"),
+        };
+
+        text += &format!("spikes (more than {} from the mean of the previous {}): {}\n", self.options.spike_threshold, self.options.spike_window, self.spikes.len());
+        for spike in &self.spikes {
+            text += &format!("  measurement {}: {} (mean {:.2})\n", spike.index + 1, spike.depth, spike.rolling_mean);
+        }
+
+        return text;
+    }
+
+    /// One row per measurement with its moving average, the rolling mean of the spike detection and whether it is
+    /// a spike, values that do not exist yet are empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("measurement,depth,moving_average,rolling_mean,spike\n");
+        let window = self.options.average_window;
+
+        for (index, depth) in self.depths.iter().enumerate() {
+            let moving_average = if index + 1 >= window { format!("{:.2}", self.moving_averages[index + 1 - window]) } else { String::new() };
+            let rolling_mean = self.rolling_means[index].map(|mean| format!("{:.2}", mean)).unwrap_or_default();
+            let spike = self.rolling_means[index].is_some_and(|mean| (*depth as f64 - mean).abs() > self.options.spike_threshold);
+
+            csv += &format!("{},{},{},{},{}\n", index + 1, depth, moving_average, rolling_mean, spike as u8);
+        }
+
+        return csv;
+    }
+}
+
+#[test]
+fn test_day1_stats_example() {
+    let input = util::read_input_file("examples/day1/example.txt").unwrap();
+    let depths = util::parse_lines::<i32>(&input).unwrap();
+
+    let report = depth_report(&depths, &StatsOptions { average_window: 3, spike_window: 3, spike_threshold: 25.0 }).unwrap();
+
+    assert_eq!((report.min, report.max, report.mean), (199, 269, 225.6));
+    assert_eq!(report.moving_averages[0], 607.0 / 3.0);
+    assert_eq!(report.moving_averages.len(), 8);
+    assert_eq!(report.longest_increasing_run, (0, 4));
+    assert_eq!(report.largest_drop, Some((4, 10)));
+    assert_eq!(report.spikes.iter().map(|spike| spike.depth).collect::<Vec<i32>>(), vec![240, 269]);
+}
+
+#[test]
+fn test_day1_stats_negative_depths() {
+    let depths = vec![-5, -5, -4, -3, -20, -19, 100, -1];
+
+    let report = depth_report(&depths, &StatsOptions { average_window: 2, spike_window: 2, spike_threshold: 10.0 }).unwrap();
+
+    assert_eq!((report.min, report.max), (-20, 100));
+    assert_eq!(report.moving_averages, vec![-5.0, -4.5, -3.5, -11.5, -19.5, 40.5, 49.5]);
+    assert_eq!(report.longest_increasing_run, (1, 3));
+    assert_eq!(report.largest_drop, Some((7, 101)));
+    assert_eq!(report.spikes.iter().map(|spike| spike.index).collect::<Vec<usize>>(), vec![4, 6, 7]);
+
+    assert!(depth_report(&vec![], &StatsOptions::default()).is_err());
+    assert!(depth_report(&depths, &StatsOptions { average_window: 0, ..StatsOptions::default() }).is_err());
+}
+
+#[test]
+fn test_day1_stats_extreme_depths() {
+    let depths = vec![i32::MAX, i32::MIN, 0, i32::MIN + 1];
+
+    let report = depth_report(&depths, &StatsOptions { average_window: 2, spike_window: 1, spike_threshold: 10.0 }).unwrap();
+
+    assert_eq!((report.min, report.max), (i32::MIN, i32::MAX));
+    assert_eq!(report.largest_drop, Some((1, u32::MAX as i64)));
+    assert_eq!(report.moving_averages, vec![-0.5, i32::MIN as f64 / 2.0, (i32::MIN + 1) as f64 / 2.0]);
+    assert!(report.to_text().contains("largest drop: 4294967295 at measurement 2\n"));
+}
+
+#[test]
+fn test_day1_stats_output() {
+    let report = depth_report(&vec![3, 1, 2, 30], &StatsOptions { average_window: 2, spike_window: 2, spike_threshold: 5.0 }).unwrap();
+
+    assert_eq!(report.to_text(), "measurements: 4\nmin: 1\nmax: 30\nmean: 9.00\n\
+        moving average of 2: first 2.00, last 16.00, min 1.50, max 16.00\n\
+        longest increasing run: 3 measurements from measurement 2\n\
+        largest drop: 2 at measurement 2\n\
+        spikes (more than 5 from the mean of the previous 2): 1\n  measurement 4: 30 (mean 1.50)\n");
+    assert_eq!(report.to_csv(), "measurement,depth,moving_average,rolling_mean,spike\n\
+        1,3,,,0\n2,1,2.00,,0\n3,2,1.50,2.00,0\n4,30,16.00,1.50,1\n");
+}
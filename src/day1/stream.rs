@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, Write};
+
+use crate::error::Error;
+use crate::util;
+
+/// Counts the increases of one window size while the measurements arrive, it only keeps the last `window_size`
+/// measurements (see `count_window_increases`).
+#[derive(Clone, Debug)]
+pub struct WindowCounter {
+    window_size: usize,
+    recent: VecDeque<i64>,
+    increases: u64,
+}
+
+impl WindowCounter {
+    pub fn new(window_size: usize) -> WindowCounter {
+        return WindowCounter { window_size, recent: VecDeque::new(), increases: 0 };
+    }
+
+    pub fn push(&mut self, measurement: i64) {
+        if self.window_size == 0 {
+            return;
+        }
+
+        if self.recent.len() == self.window_size {
+            let first = self.recent.pop_front().unwrap();
+            if measurement > first {
+                self.increases += 1;
+            }
+        }
+
+        self.recent.push_back(measurement);
+    }
+
+    pub fn window_size(&self) -> usize {
+        return self.window_size;
+    }
+
+    pub fn increases(&self) -> u64 {
+        return self.increases;
+    }
+}
+
+/// Running counts of increases for several window sizes over a stream of measurements.
+#[derive(Clone, Debug)]
+pub struct DepthStream {
+    counters: Vec<WindowCounter>,
+    measurements: u64,
+}
+
+impl DepthStream {
+    pub fn new(window_sizes: &[usize]) -> DepthStream {
+        return DepthStream { counters: window_sizes.iter().map(|size| WindowCounter::new(*size)).collect(), measurements: 0 };
+    }
+
+    pub fn push(&mut self, measurement: i64) {
+        for counter in &mut self.counters {
+            counter.push(measurement);
+        }
+        self.measurements += 1;
+    }
+
+    pub fn measurements(&self) -> u64 {
+        return self.measurements;
+    }
+
+    /// The window sizes with their number of increases so far.
+    pub fn increases(&self) -> Vec<(usize, u64)> {
+        return self.counters.iter().map(|counter| (counter.window_size(), counter.increases())).collect();
+    }
+
+    /// One line like "10 measurements, window 1: 7 increases, window 3: 5 increases".
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{} measurements", self.measurements);
+        for (window_size, increases) in self.increases() {
+            summary += &format!(", window {}: {} increases", window_size, increases);
+        }
+
+        return summary;
+    }
+}
+
+/// Reads one measurement per line until the reader ends and writes a summary every `summary_every` measurements
+/// (never if 0) and a final one. Blank lines are skipped, the first invalid line stops the stream.
+pub fn analyze<R: BufRead, W: Write>(reader: R, window_sizes: &[usize], summary_every: u64, output: &mut W) -> Result<DepthStream, Error> {
+    let mut stream = DepthStream::new(window_sizes);
+    let write_error = |source| Error::Io { path: String::from("<stdout>"), source };
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|source| Error::Io { path: String::from("<input>"), source })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        stream.push(util::parse_value::<i64>(&line, i + 1, 1)?);
+
+        if summary_every > 0 && stream.measurements().is_multiple_of(summary_every) {
+            writeln!(output, "{}", stream.summary()).map_err(write_error)?;
+            output.flush().map_err(write_error)?;
+        }
+    }
+
+    // The last periodic summary is already the final one
+    if summary_every == 0 || stream.measurements() == 0 || !stream.measurements().is_multiple_of(summary_every) {
+        writeln!(output, "{}", stream.summary()).map_err(write_error)?;
+    }
+
+    return Ok(stream);
+}
+
+#[test]
+fn test_day1_stream_example() {
+    let input = util::read_input_file("examples/day1/example.txt").unwrap();
+    let mut output: Vec<u8> = vec![];
+
+    let stream = analyze(input.as_bytes(), &[1, 3], 4, &mut output).unwrap();
+
+    assert_eq!(stream.increases(), vec![(1, 7), (3, 5)]);
+    assert_eq!(String::from_utf8(output).unwrap(), "4 measurements, window 1: 3 increases, window 3: 1 increases\n\
+        8 measurements, window 1: 6 increases, window 3: 3 increases\n\
+        10 measurements, window 1: 7 increases, window 3: 5 increases\n");
+}
+
+#[test]
+fn test_day1_stream_matches_window_increases() {
+    let input = util::read_input_file("day1/input.txt").unwrap();
+    let measurements = util::parse_lines::<i64>(&input).unwrap();
+    let window_sizes = [0, 1, 2, 3, 10];
+
+    let stream = analyze(input.as_bytes(), &window_sizes, 0, &mut vec![]).unwrap();
+
+    for (window_size, increases) in stream.increases() {
+        assert_eq!(increases as usize, super::count_window_increases(&measurements, window_size));
+    }
+
+    // The window only grows with the measurements
+    let stream = analyze("1\n2\n".as_bytes(), &[usize::MAX], 0, &mut vec![]).unwrap();
+    assert_eq!(stream.increases(), vec![(usize::MAX, 0)]);
+
+    let error = analyze("1\n2\n\nx\n3\n".as_bytes(), &window_sizes, 0, &mut vec![]).unwrap_err();
+    assert_eq!(error.to_string(), "line 4, column 1: invalid number 'x'");
+}
@@ -1,34 +1,58 @@
 use itertools::Itertools;
 
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main()
+pub struct Day10;
+
+impl Solution for Day10
 {
-    let input = util::read_input_file("day10.txt");
-    let lines = util::split_string_by_string(&input, "\n");
+    type Input = Vec<String>;
 
-    let error_points = get_syntax_error_point_sum(&lines);
-    println!("Part 1: Solution={}", error_points);
+    fn parse(input: &str) -> Result<Vec<String>, Error>
+    {
+        return util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line)| {
+            if let Some(column) = line.find(|c| !"()[]{}<>".contains(c))
+            {
+                return Err(Error::parse(*line_number, column + 1, "expected only the characters ()[]{}<>"));
+            }
 
-    let incomplete_lines = get_incomplete_lines(&lines);
-    let incomplete_scores = get_incomplete_scores(&incomplete_lines);
-    let middle_score = get_middle_score(&incomplete_scores);
-    println!("Part 2: Solution={}", middle_score);
+            if line.starts_with(|c| !is_opening_character(c))
+            {
+                return Err(Error::parse(*line_number, 1, "a line must not start with a closing character"));
+            }
+
+            return Ok(line.to_string());
+        }));
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, Error>
+    {
+        return Ok(get_syntax_error_point_sum(lines).into());
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, Error>
+    {
+        let incomplete_lines = get_incomplete_lines(lines);
+        let incomplete_scores = get_incomplete_scores(&incomplete_lines);
+        return Ok(get_middle_score(&incomplete_scores).into());
+    }
 }
 
-fn get_syntax_error_point_sum(lines: &Vec<String>) -> i32
+pub fn get_syntax_error_point_sum(lines: &Vec<String>) -> i32
 {
     let mut error_points = 0;
 
     for line in lines
     {
-        error_points += get_syntax_error_points_from_line(&line);
+        error_points += get_syntax_error_points_from_line(line);
     }
 
     return error_points;
 }
 
-fn get_syntax_error_points_from_line(line: &String) -> i32
+pub fn get_syntax_error_points_from_line(line: &String) -> i32
 {
     let mut open_chunks: Vec<char> = vec![];
     let mut has_syntax_error = false;
@@ -63,13 +87,13 @@ fn get_syntax_error_points_from_line(line: &String) -> i32
     return 0;
 }
 
-fn get_incomplete_lines(lines: &Vec<String>) -> Vec<String>
+pub fn get_incomplete_lines(lines: &Vec<String>) -> Vec<String>
 {
     let mut incomplete_lines: Vec<String> = vec![];
 
     for line in lines
     {
-        let error_points = get_syntax_error_points_from_line(&line);
+        let error_points = get_syntax_error_points_from_line(line);
         if error_points == 0
         {
             incomplete_lines.push(line.to_string());
@@ -79,14 +103,14 @@ fn get_incomplete_lines(lines: &Vec<String>) -> Vec<String>
     return incomplete_lines;
 }
 
-fn get_incomplete_scores(incomplete_lines: &Vec<String>) -> Vec<u64>
+pub fn get_incomplete_scores(incomplete_lines: &Vec<String>) -> Vec<u64>
 {
     let mut scores: Vec<u64> = vec![];
 
     for line in incomplete_lines
     {
         let mut score: u64 = 0;
-        let missing_characters = get_completion_characters(&line);
+        let missing_characters = get_completion_characters(line);
         for character in missing_characters
         {
             score = score * 5 + get_completion_points(character);
@@ -97,13 +121,13 @@ fn get_incomplete_scores(incomplete_lines: &Vec<String>) -> Vec<u64>
     return scores;
 }
 
-fn get_middle_score(scores: &Vec<u64>) -> u64
+pub fn get_middle_score(scores: &Vec<u64>) -> u64
 {
-    let sorted_scores: Vec<u64> = scores.iter().sorted().map(|x| *x).collect();
+    let sorted_scores: Vec<u64> = scores.iter().sorted().copied().collect();
     return sorted_scores[sorted_scores.len() / 2];
 }
 
-fn get_completion_characters(line: &String) -> Vec<char>
+pub fn get_completion_characters(line: &String) -> Vec<char>
 {
     let mut open_chunks: Vec<char> = vec![];
 
@@ -122,9 +146,8 @@ fn get_completion_characters(line: &String) -> Vec<char>
 
     let mut completion_characters: Vec<char> = vec![];
 
-    while !open_chunks.is_empty()
+    while let Some(opening_character) = open_chunks.pop()
     {
-        let opening_character = open_chunks.pop().unwrap();
         let closing_character = get_closing_character(opening_character);
         completion_characters.push(closing_character);
     }
@@ -132,16 +155,12 @@ fn get_completion_characters(line: &String) -> Vec<char>
     return completion_characters;
 }
 
-fn is_opening_character(character: char) -> bool
+pub fn is_opening_character(character: char) -> bool
 {
-    return match character
-    {
-        '(' | '[' | '{' | '<' => true,
-        _ => false,
-    }
+    return matches!(character, '(' | '[' | '{' | '<');
 }
 
-fn get_opening_character(character: char) -> char
+pub fn get_opening_character(character: char) -> char
 {
     return match character
     {
@@ -153,7 +172,7 @@ fn get_opening_character(character: char) -> char
     }
 }
 
-fn get_closing_character(character: char) -> char
+pub fn get_closing_character(character: char) -> char
 {
     return match character
     {
@@ -165,7 +184,7 @@ fn get_closing_character(character: char) -> char
     }
 }
 
-fn get_syntax_error_points(character: char) -> i32
+pub fn get_syntax_error_points(character: char) -> i32
 {
     return match character
     {
@@ -177,7 +196,7 @@ fn get_syntax_error_points(character: char) -> i32
     }
 }
 
-fn get_completion_points(character: char) -> u64
+pub fn get_completion_points(character: char) -> u64
 {
     return match character
     {
@@ -192,17 +211,7 @@ fn get_completion_points(character: char) -> u64
 #[test]
 fn test_day10_part1_example()
 {
-    let input = "
-[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]".to_string();
+    let input = util::read_input_file("examples/day10/example.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let error_points = get_syntax_error_point_sum(&lines);
 
@@ -212,7 +221,7 @@ fn test_day10_part1_example()
 #[test]
 fn test_day10_part1_solution()
 {
-    let input = util::read_input_file("day10.txt");
+    let input = util::read_input_file("day10/input.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let error_points = get_syntax_error_point_sum(&lines);
 
@@ -222,17 +231,7 @@ fn test_day10_part1_solution()
 #[test]
 fn test_day10_part2_example()
 {
-    let input = "
-[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]".to_string();
+    let input = util::read_input_file("examples/day10/example.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let incomplete_lines = get_incomplete_lines(&lines);
     let incomplete_scores = get_incomplete_scores(&incomplete_lines);
@@ -244,7 +243,7 @@ fn test_day10_part2_example()
 #[test]
 fn test_day10_part2_solution()
 {
-    let input = util::read_input_file("day10.txt");
+    let input = util::read_input_file("day10/input.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let incomplete_lines = get_incomplete_lines(&lines);
     let incomplete_scores = get_incomplete_scores(&incomplete_lines);
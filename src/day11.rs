@@ -1,77 +1,63 @@
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-trait OctopusLogic
+pub trait OctopusLogic
 {
     fn step(&mut self);
     fn propagate_flash(&mut self, x: usize, y: usize, increase: bool);
     fn all_flashing(&mut self) -> bool;
 }
 
-struct Grid
+pub struct Grid
 {
-    grid: Vec<Vec<i32>>,
-    flashed: Vec<Vec<bool>>,
-    num_of_flashes: u64,
+    pub grid: util::Grid<i32>,
+    pub flashed: util::Grid<bool>,
+    pub num_of_flashes: u64,
+}
+
+impl Grid
+{
+    pub fn new(grid: util::Grid<i32>) -> Grid
+    {
+        let flashed = util::Grid::new(grid.width(), grid.height(), false);
+        return Grid { grid, flashed, num_of_flashes: 0 };
+    }
 }
 
 impl OctopusLogic for Grid
 {
     fn step(&mut self)
     {
-        let size = self.grid.len();
-        self.flashed = vec![vec![false; size]; size];
-        
-        for y in 0..size
-        {
-            for x in 0..size
-            {
-                self.grid[y][x] += 1;
-            }
-        }
+        self.flashed = util::Grid::new(self.grid.width(), self.grid.height(), false);
 
-        for y in 0..size
+        self.grid.iter_mut().for_each(|energy| *energy += 1);
+
+        for (x, y) in self.grid.positions()
         {
-            for x in 0..size
-            {
-                self.propagate_flash(x, y, false);
-            }
+            self.propagate_flash(x, y, false);
         }
     }
 
     fn propagate_flash(&mut self, x: usize, y: usize, increase: bool)
     {
-        if self.flashed[y][x] == true
+        if self.flashed[(x, y)] == true
         {
             return;
         }
 
         if increase
         {
-            self.grid[y][x] += 1;
+            self.grid[(x, y)] += 1;
         }
 
-        if self.grid[y][x] > 9
+        if self.grid[(x, y)] > 9
         {
-            self.flashed[y][x] = true;
+            self.flashed[(x, y)] = true;
             self.num_of_flashes += 1;
-            self.grid[y][x] = 0;
-
-            let size = self.grid.len();
-            let mut adjacent_indexes: Vec<(usize, usize)> = vec![];
+            self.grid[(x, y)] = 0;
 
-            for i in 0..=2
-            {
-                for j in 0..=2
-                {
-                    if x + i < 1 || y + j < 1 || x + i > size || y + j > size || (i == 1 && j == 1)
-                    {
-                        continue;
-                    }
-
-                    adjacent_indexes.push((x + i - 1, y + j - 1));
-                }
-            }
-    
+            let adjacent_indexes: Vec<(usize, usize)> = self.grid.neighbours8(x, y).collect();
             for adjacent_index in adjacent_indexes
             {
                 self.propagate_flash(adjacent_index.0, adjacent_index.1, true);
@@ -81,55 +67,40 @@ impl OctopusLogic for Grid
 
     fn all_flashing(&mut self) -> bool
     {
-        let size = self.grid.len();
-        
-        for y in 0..size
-        {
-            for x in 0..size
-            {
-                if self.flashed[y][x] == false
-                {
-                    return false;
-                }
-            }
-        }
-
-        return true;
+        return self.flashed.iter().all(|flashed| *flashed);
     }
 }
 
-fn main()
-{
-    let input = util::read_input_file("day11.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let array = convert_to_int_array(&lines);
-    let mut grid = Grid { grid: array, flashed: vec![], num_of_flashes: 0 };
+pub struct Day11;
 
-    let num_of_flashes = get_num_of_flashes(&mut grid,  100);
-    println!("Part 1: Solution={}", num_of_flashes);
+impl Solution for Day11
+{
+    type Input = util::Grid<i32>;
 
-    let step = get_step_where_all_flashing(&mut grid, 1000);
-    println!("Part 2: Solution={}", step);
-}
+    fn parse(input: &str) -> Result<util::Grid<i32>, Error>
+    {
+        return convert_to_int_array(input);
+    }
 
-fn convert_to_int_array(lines: &Vec<String>) -> Vec<Vec<i32>>
-{
-    let size = lines.len();
-    let mut array: Vec<Vec<i32>> = vec![vec![0; size]; size];
+    fn part1(array: &util::Grid<i32>) -> Result<Answer, Error>
+    {
+        let mut grid = Grid::new(array.clone());
+        return Ok(get_num_of_flashes(&mut grid, 100).into());
+    }
 
-    for y in 0..lines.len()
+    fn part2(array: &util::Grid<i32>) -> Result<Answer, Error>
     {
-        let numbers: Vec<i32> = lines[y].chars().map(|x| x.to_digit(10).unwrap() as i32).collect();
-        for x in 0..numbers.len()
-        {
-            array[y][x] = numbers[x];
-        }
+        let mut grid = Grid::new(array.clone());
+        return Ok(get_step_where_all_flashing(&mut grid, 1000).into());
     }
+}
 
-    return array;
+pub fn convert_to_int_array(input: &str) -> Result<util::Grid<i32>, Error>
+{
+    return util::Grid::parse_digits(input);
 }
 
-fn get_num_of_flashes(grid: &mut Grid, steps: usize) -> u64
+pub fn get_num_of_flashes(grid: &mut Grid, steps: usize) -> u64
 {
     for _ in 0..steps
     {
@@ -139,7 +110,7 @@ fn get_num_of_flashes(grid: &mut Grid, steps: usize) -> u64
     return grid.num_of_flashes;
 }
 
-fn get_step_where_all_flashing(grid: &mut Grid, max_steps: usize) -> usize
+pub fn get_step_where_all_flashing(grid: &mut Grid, max_steps: usize) -> usize
 {
     for step in 1..=max_steps
     {
@@ -156,20 +127,9 @@ fn get_step_where_all_flashing(grid: &mut Grid, max_steps: usize) -> usize
 #[test]
 fn test_day11_part1_example()
 {
-    let input = "
-5483143223
-2745854711
-5264556173
-6141336146
-6357385478
-4167524645
-2176841721
-6882881134
-4846848554
-5283751526".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let array = convert_to_int_array(&lines);
-    let mut grid = Grid { grid: array, flashed: vec![], num_of_flashes: 0 };
+    let input = util::read_input_file("examples/day11/example.txt").unwrap();
+    let array = convert_to_int_array(&input).unwrap();
+    let mut grid = Grid::new(array);
     let num_of_flashes = get_num_of_flashes(&mut grid, 100);
 
     assert_eq!(num_of_flashes, 1656);
@@ -178,10 +138,9 @@ fn test_day11_part1_example()
 #[test]
 fn test_day11_part1_solution()
 {
-    let input = util::read_input_file("day11.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let array = convert_to_int_array(&lines);
-    let mut grid = Grid { grid: array, flashed: vec![], num_of_flashes: 0 };
+    let input = util::read_input_file("day11/input.txt").unwrap();
+    let array = convert_to_int_array(&input).unwrap();
+    let mut grid = Grid::new(array);
     let num_of_flashes = get_num_of_flashes(&mut grid,  100);
 
     assert_eq!(num_of_flashes, 1743);
@@ -190,20 +149,9 @@ fn test_day11_part1_solution()
 #[test]
 fn test_day11_part2_example()
 {
-    let input = "
-5483143223
-2745854711
-5264556173
-6141336146
-6357385478
-4167524645
-2176841721
-6882881134
-4846848554
-5283751526".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let array = convert_to_int_array(&lines);
-    let mut grid = Grid { grid: array, flashed: vec![], num_of_flashes: 0 };
+    let input = util::read_input_file("examples/day11/example.txt").unwrap();
+    let array = convert_to_int_array(&input).unwrap();
+    let mut grid = Grid::new(array);
     let step = get_step_where_all_flashing(&mut grid, 1000);
 
     assert_eq!(step, 195);
@@ -212,10 +160,9 @@ fn test_day11_part2_example()
 #[test]
 fn test_day11_part2_solution()
 {
-    let input = util::read_input_file("day11.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let array = convert_to_int_array(&lines);
-    let mut grid = Grid { grid: array, flashed: vec![], num_of_flashes: 0 };
+    let input = util::read_input_file("day11/input.txt").unwrap();
+    let array = convert_to_int_array(&input).unwrap();
+    let mut grid = Grid::new(array);
     let step = get_step_where_all_flashing(&mut grid, 1000);
 
     assert_eq!(step, 364);
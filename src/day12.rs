@@ -1,38 +1,49 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-mod util;
+use crate::error::Error;
+#[cfg(test)]
+use crate::generate;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
 #[derive(Clone)]
-struct Cave
+pub struct Cave
 {
-    name: String,
-    connections: HashSet<String>,
-    is_big: bool,
+    pub name: String,
+    pub connections: HashSet<String>,
+    pub is_big: bool,
 }
 
-fn main()
+pub struct Day12;
+
+impl Solution for Day12
 {
-    let input = util::read_input_file("day12.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    type Input = HashMap<String, Cave>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Cave>, Error>
+    {
+        return parse_caves(input);
+    }
 
-    let paths_once = find_paths_once(&caves);
-    println!("Part 1: Solution={}", paths_once);
+    fn part1(caves: &HashMap<String, Cave>) -> Result<Answer, Error>
+    {
+        return Ok(find_paths_once(caves).into());
+    }
 
-    let paths_twice = find_paths_twice(&caves);
-    println!("Part 2: Solution={}", paths_twice);
+    fn part2(caves: &HashMap<String, Cave>) -> Result<Answer, Error>
+    {
+        return Ok(find_paths_twice(caves).into());
+    }
 }
 
-fn parse_caves(lines: &Vec<String>) -> HashMap<String, Cave>
+pub fn parse_caves(input: &str) -> Result<HashMap<String, Cave>, Error>
 {
     let mut all_caves: HashMap<String, Cave> = HashMap::new();
+    let connections = util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line)| parse_connection(line, *line_number)))?;
 
-    for line in lines
+    for parts in connections
     {
-        let parts = util::split_string_by_string(line, "-");
-        assert_eq!(parts.len(), 2);
-
         let is_big1 = parts[0].to_ascii_uppercase() == parts[0];
         let mut cave1 = if all_caves.contains_key(&parts[0]) { all_caves[&parts[0]].clone() } else { Cave { name: parts[0].to_string(), connections: HashSet::new(), is_big: is_big1 } };
 
@@ -46,15 +57,46 @@ fn parse_caves(lines: &Vec<String>) -> HashMap<String, Cave>
         all_caves.insert(cave2.name.to_string(), cave2);
     }
 
-    return all_caves;
+    for name in ["start", "end"]
+    {
+        if !all_caves.contains_key(name)
+        {
+            return Err(Error::input(format!("missing the '{}' cave", name)));
+        }
+    }
+
+    return Ok(all_caves);
+}
+
+// Returns the names of the two connected caves
+pub fn parse_connection(line: &str, line_number: usize) -> Result<Vec<String>, Error>
+{
+    let parts = util::split_string_by_string(line, "-");
+    if parts.len() != 2 || line.split('-').count() != 2
+    {
+        return Err(Error::parse(line_number, 1, format!("expected a connection 'a-b', found '{}'", line)));
+    }
+
+    if let Some(column) = line.find(|c: char| !c.is_ascii_alphabetic() && c != '-')
+    {
+        return Err(Error::parse(line_number, column + 1, "cave names must only contain letters"));
+    }
+
+    // Two connected big caves could be visited back and forth forever
+    if parts.iter().all(|name| name.to_ascii_uppercase() == *name)
+    {
+        return Err(Error::parse(line_number, 1, format!("the big caves {} and {} must not be connected", parts[0], parts[1])));
+    }
+
+    return Ok(parts);
 }
 
-fn find_paths_once(caves: &HashMap<String, Cave>) -> usize
+pub fn find_paths_once(caves: &HashMap<String, Cave>) -> usize
 {
-    return count_sub_paths_once("start", &caves, &mut vec![]);
+    return count_sub_paths_once("start", caves, &mut vec![]);
 }
 
-fn count_sub_paths_once(cave_name: &str, caves: &HashMap<String, Cave>, visited: &mut Vec<String>) -> usize
+pub fn count_sub_paths_once(cave_name: &str, caves: &HashMap<String, Cave>, visited: &mut Vec<String>) -> usize
 {
     if cave_name == "end"
     {
@@ -79,7 +121,7 @@ fn count_sub_paths_once(cave_name: &str, caves: &HashMap<String, Cave>, visited:
     return count;
 }
 
-fn find_paths_twice(caves: &HashMap<String, Cave>) -> usize
+pub fn find_paths_twice(caves: &HashMap<String, Cave>) -> usize
 {
     let small_caves = caves.values().filter(|x| x.name != "start" && x.name != "end" && !x.is_big);
 
@@ -97,7 +139,7 @@ fn find_paths_twice(caves: &HashMap<String, Cave>) -> usize
     return path_count;
 }
 
-fn count_sub_paths_twice(cave_name: &str, caves: &HashMap<String, Cave>, visited: &mut Vec<String>, double_visit_cave_name: &str, has_double_visited: &mut bool) -> usize
+pub fn count_sub_paths_twice(cave_name: &str, caves: &HashMap<String, Cave>, visited: &mut Vec<String>, double_visit_cave_name: &str, has_double_visited: &mut bool) -> usize
 {
     if cave_name == "end"
     {
@@ -133,18 +175,47 @@ fn count_sub_paths_twice(cave_name: &str, caves: &HashMap<String, Cave>, visited
     return count;
 }
 
+// Enumerates all paths one by one. Small caves are visited at most once, with `allow_twice` a single one of them
+// (but not start or end) twice.
+pub fn enumerate_paths(caves: &HashMap<String, Cave>, allow_twice: bool) -> usize
+{
+    return enumerate_sub_paths(caves, &mut vec![String::from("start")], allow_twice);
+}
+
+fn enumerate_sub_paths(caves: &HashMap<String, Cave>, path: &mut Vec<String>, allow_twice: bool) -> usize
+{
+    let cave_name = path.last().unwrap().to_string();
+    if cave_name == "end"
+    {
+        return 1;
+    }
+
+    let mut count: usize = 0;
+    for connected_cave_name in &caves[&cave_name].connections
+    {
+        let is_small = !caves[connected_cave_name].is_big;
+        if is_small && path.contains(connected_cave_name)
+        {
+            let has_visited_twice = path.iter().any(|name| !caves[name].is_big && path.iter().filter(|x| *x == name).count() > 1);
+            if !allow_twice || has_visited_twice || connected_cave_name == "start"
+            {
+                continue;
+            }
+        }
+
+        path.push(connected_cave_name.to_string());
+        count += enumerate_sub_paths(caves, path, allow_twice);
+        path.pop();
+    }
+
+    return count;
+}
+
 #[test]
 fn test_day12_part1_example1()
 {
-    let input = "start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("examples/day12/example1.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_once(&caves);
 
     assert_eq!(paths, 10);
@@ -153,18 +224,8 @@ b-end".to_string();
 #[test]
 fn test_day12_part1_example2()
 {
-    let input = "dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("examples/day12/example2.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_once(&caves);
 
     assert_eq!(paths, 19);
@@ -173,26 +234,8 @@ kj-dc".to_string();
 #[test]
 fn test_day12_part1_example3()
 {
-    let input = "fs-end
-he-DX
-fs-he
-start-DX
-pj-DX
-end-zg
-zg-sl
-zg-pj
-pj-he
-RW-he
-fs-DX
-pj-RW
-zg-RW
-start-pj
-he-WI
-zg-he
-pj-fs
-start-RW".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("examples/day12/example3.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_once(&caves);
 
     assert_eq!(paths, 226);
@@ -201,9 +244,8 @@ start-RW".to_string();
 #[test]
 fn test_day12_part1_solution()
 {
-    let input = util::read_input_file("day12.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("day12/input.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_once(&caves);
 
     assert_eq!(paths, 4754);
@@ -212,15 +254,8 @@ fn test_day12_part1_solution()
 #[test]
 fn test_day12_part2_example1()
 {
-    let input = "start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("examples/day12/example1.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_twice(&caves);
 
     assert_eq!(paths, 36);
@@ -229,18 +264,8 @@ b-end".to_string();
 #[test]
 fn test_day12_part2_example2()
 {
-    let input = "dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("examples/day12/example2.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_twice(&caves);
 
     assert_eq!(paths, 103);
@@ -249,26 +274,8 @@ kj-dc".to_string();
 #[test]
 fn test_day12_part2_example3()
 {
-    let input = "fs-end
-he-DX
-fs-he
-start-DX
-pj-DX
-end-zg
-zg-sl
-zg-pj
-pj-he
-RW-he
-fs-DX
-pj-RW
-zg-RW
-start-pj
-he-WI
-zg-he
-pj-fs
-start-RW".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("examples/day12/example3.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_twice(&caves);
 
     assert_eq!(paths, 3509);
@@ -277,10 +284,35 @@ start-RW".to_string();
 #[test]
 fn test_day12_part2_solution()
 {
-    let input = util::read_input_file("day12.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let caves = parse_caves(&lines);
+    let input = util::read_input_file("day12/input.txt").unwrap();
+    let caves = parse_caves(&input).unwrap();
     let paths = find_paths_twice(&caves);
 
     assert_eq!(paths, 143562);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_day12_part1_reference()
+{
+    let input = util::read_input_file("examples/day12/example1.txt").unwrap();
+    assert_eq!(enumerate_paths(&parse_caves(&input).unwrap(), false), 10);
+
+    let solve = |input: &str| find_paths_once(&parse_caves(input).unwrap());
+    let reference = |input: &str| enumerate_paths(&parse_caves(input).unwrap(), false);
+
+    let counterexample = generate::find_counterexample(12, &[2, 4, 6, 8], 20, solve, reference);
+    assert!(counterexample.is_none(), "{:?}", counterexample);
+}
+
+#[test]
+fn test_day12_part2_reference()
+{
+    let input = util::read_input_file("examples/day12/example1.txt").unwrap();
+    assert_eq!(enumerate_paths(&parse_caves(&input).unwrap(), true), 36);
+
+    let solve = |input: &str| find_paths_twice(&parse_caves(input).unwrap());
+    let reference = |input: &str| enumerate_paths(&parse_caves(input).unwrap(), true);
+
+    let counterexample = generate::find_counterexample(12, &[2, 4, 6, 8], 20, solve, reference);
+    assert!(counterexample.is_none(), "{:?}", counterexample);
+}
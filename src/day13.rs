@@ -1,172 +1,216 @@
-use substring::Substring;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+use crate::util::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
 
-mod util;
+pub struct Day13;
 
-fn main() {
-    let input = util::read_input_file("day13.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let (mut paper, instructions) = extract_paper_and_instructions(&lines);
+impl Solution for Day13 {
+    type Input = (Grid<bool>, Vec<Fold>);
 
-    for i in 0..instructions.len() {
-        paper = fold_paper(&paper, &instructions[i]);
-        let sum: usize = paper.iter().map(|y| y.iter().filter(|x| **x == true).count()).sum();
+    fn parse(input: &str) -> Result<(Grid<bool>, Vec<Fold>), Error> {
+        return extract_paper_and_instructions(input);
+    }
 
-        if i == 0 {
-            println!("Part 1: Solution={}", sum);
-        }
+    fn part1((paper, instructions): &(Grid<bool>, Vec<Fold>)) -> Result<Answer, Error> {
+        let folded_paper = fold_paper(paper, &instructions[0]);
+        return Ok(count_dots(&folded_paper).into());
     }
 
-    let final_output = print_paper(&paper);
-    println!("Part 2:\n{}", final_output);
+    fn part2((paper, instructions): &(Grid<bool>, Vec<Fold>)) -> Result<Answer, Error> {
+        let mut paper = paper.clone();
+
+        for instruction in instructions {
+            paper = fold_paper(&paper, instruction);
+        }
+
+        return Ok(print_paper(&paper).into());
+    }
 }
 
-fn extract_paper_and_instructions(lines: &Vec<String>) -> (Vec<Vec<bool>>, Vec<String>) {
-    let dots: Vec<String> = lines.iter().filter(|x| !x.starts_with("fold along")).map(|x| String::from(x)).collect();
-    let instructions: Vec<String> = lines.iter().filter(|x| x.starts_with("fold along")).map(|x| String::from(x)).collect();
-    let (width, height) = get_paper_size_from_instructions(&instructions);
-    let paper = fill_paper(&dots, width, height);
-    return (paper, instructions);
+pub fn extract_paper_and_instructions(input: &str) -> Result<(Grid<bool>, Vec<Fold>), Error> {
+    let lines = util::numbered_lines(input);
+    let dots: Vec<(usize, &str)> = lines.iter().filter(|(_, x)| !x.starts_with("fold along")).copied().collect();
+    let numbered_instructions = util::collect_results(lines.iter().filter(|(_, x)| x.starts_with("fold along")).map(|(line_number, line)| {
+        return Ok((*line_number, parse_instruction(line, *line_number)?));
+    }))?;
+
+    let instructions: Vec<Fold> = numbered_instructions.iter().map(|(_, instruction)| *instruction).collect();
+    let (width, height) = get_paper_size_from_instructions(&instructions)?;
+
+    return match (validate_instructions(&numbered_instructions, width, height), fill_paper(&dots, width, height)) {
+        (Ok(()), Ok(paper)) => Ok((paper, instructions)),
+        (folds, paper) => {
+            // The folds are validated first, but the report lists the problems in the order of the lines
+            let mut problems = match Error::combine(folds.err().into_iter().chain(paper.err()).collect()) {
+                Error::Multiple { errors } => errors,
+                error => vec![error],
+            };
+            problems.sort_by_key(|problem| match problem {
+                Error::Parse { line, .. } => *line,
+                _ => 0,
+            });
+            Err(Error::combine(problems))
+        },
+    };
 }
 
-fn fill_paper(dots: &Vec<String>, width: usize, height: usize) -> Vec<Vec<bool>> {
-    let mut paper: Vec<Vec<bool>> = vec![vec![false; width]; height];
+pub fn fill_paper(dots: &[(usize, &str)], width: usize, height: usize) -> Result<Grid<bool>, Error> {
+    let mut paper: Grid<bool> = Grid::new(width, height, false);
 
-    for dot in dots {
-        let dot_parts = util::split_generics_by_string::<usize>(dot, ",");
-        assert_eq!(dot_parts.len(), 2);
+    let positions = util::collect_results(dots.iter().map(|(line_number, dot)| {
+        let dot_parts = util::parse_separated::<usize>(dot, ",", *line_number, 1)?;
+        if dot_parts.len() != 2 {
+            return Err(Error::parse(*line_number, 1, format!("expected a dot 'x,y', found '{}'", dot)));
+        }
 
         let x = dot_parts[0];
         let y = dot_parts[1];
-        paper[y][x] = true;
+        if x >= width || y >= height {
+            return Err(Error::parse(*line_number, 1, format!("dot {},{} is outside of the {}x{} paper", x, y, width, height)));
+        }
+
+        return Ok((x, y));
+    }))?;
+
+    for position in positions {
+        paper[position] = true;
     }
 
-    return paper;
+    return Ok(paper);
 }
 
-fn fold_paper(paper: &Vec<Vec<bool>>, instruction_line: &str) -> Vec<Vec<bool>> {
-    let x_size: usize;
-    let y_size: usize;
+// Every fold has to be exactly in the middle of the (already folded) paper
+fn validate_instructions(instructions: &[(usize, Fold)], width: usize, height: usize) -> Result<(), Error> {
+    let mut width = width;
+    let mut height = height;
+    let mut errors: Vec<Error> = vec![];
 
-    let (axis, number) = parse_instruction(instruction_line);
+    for (line_number, instruction) in instructions {
+        let (size, number) = match instruction {
+            Fold::X(number) => (&mut width, *number),
+            Fold::Y(number) => (&mut height, *number),
+        };
 
-    let paper_x_size = paper[0].len();
-    let paper_y_size = paper.len();
+        if *size / 2 != number {
+            errors.push(Error::parse(*line_number, 14, format!("fold at {} is not in the middle of the paper with size {}", number, size)));
+        }
 
-    if axis == "x" {
-        x_size = (paper_x_size as f32 / 2f32).floor() as usize;
-        y_size = paper_y_size;
-        assert_eq!(x_size, number as usize);
-    } else {
-        x_size = paper_x_size;
-        y_size = (paper_y_size as f32 / 2f32).floor() as usize;
-        assert_eq!(y_size, number as usize);
+        *size /= 2;
     }
 
-    let mut folded_paper: Vec<Vec<bool>> = vec![vec![false; x_size]; y_size];
-
-    for y in 0..y_size {
-        for x in 0..x_size {
-            let folded_x = if axis == "x" { paper_x_size - x - 1 } else { x };
-            let folded_y = if axis == "y" { paper_y_size - y - 1 } else { y };
-            folded_paper[y][x] = paper[y][x] || paper[folded_y][folded_x];
-        }
+    if !errors.is_empty() {
+        return Err(Error::combine(errors));
     }
 
-    return folded_paper;
+    return Ok(());
 }
 
-fn print_paper(paper: &Vec<Vec<bool>>) -> String {
-    let mut output = String::new();
-
-    for line in paper {
-        let mut dots = String::new();
-        for dot in line {
-            dots += if *dot { "#" } else { "." };
-        }
-        output += &dots;
-        output += "\n";
+pub fn fold_paper(paper: &Grid<bool>, instruction: &Fold) -> Grid<bool> {
+    let (x_size, y_size) = match *instruction {
+        Fold::X(number) => {
+            assert_eq!(paper.width() / 2, number);
+            (number, paper.height())
+        },
+        Fold::Y(number) => {
+            assert_eq!(paper.height() / 2, number);
+            (paper.width(), number)
+        },
+    };
+
+    let mut folded_paper: Grid<bool> = Grid::new(x_size, y_size, false);
+
+    for (x, y) in folded_paper.positions() {
+        let folded_x = if let Fold::X(_) = instruction { paper.width() - x - 1 } else { x };
+        let folded_y = if let Fold::Y(_) = instruction { paper.height() - y - 1 } else { y };
+        folded_paper[(x, y)] = paper[(x, y)] || paper[(folded_x, folded_y)];
     }
 
-    return output;
+    return folded_paper;
+}
+
+pub fn count_dots(paper: &Grid<bool>) -> usize {
+    return paper.iter().filter(|x| **x).count();
 }
 
-fn parse_instruction(instruction: &str) -> (&str, i32) {
-    let instruction = instruction.substring(11, instruction.len());
-    let parts: Vec<&str> = instruction.split("=").collect();
-    let axis = parts[0];
-    let number = parts[1].parse::<i32>().unwrap();
-    return (axis, number);
+pub fn print_paper(paper: &Grid<bool>) -> String {
+    return format!("{}\n", paper.map(|dot| if *dot { '#' } else { '.' }));
 }
 
-fn get_paper_size_from_instructions(instructions: &Vec<String>) -> (usize, usize) {
-    let (axis1, number1) = parse_instruction(&instructions[0]);
-    let (axis2, number2) = parse_instruction(&instructions[1]);
+pub fn parse_instruction(instruction: &str, line_number: usize) -> Result<Fold, Error> {
+    let instruction = instruction.strip_prefix("fold along ").ok_or_else(|| Error::parse(line_number, 1, "expected 'fold along'"))?;
 
-    if axis1 == "x" {
-        assert_eq!(axis2, "y");
-        return ((number1 * 2 + 1) as usize, (number2 * 2 + 1) as usize);
-    } else {
-        assert_eq!(axis2, "x");
-        return ((number2 * 2 + 1) as usize, (number1 * 2 + 1) as usize);
+    return match instruction.split_once('=') {
+        Some(("x", number)) => Ok(Fold::X(util::parse_value::<usize>(number, line_number, 14)?)),
+        Some(("y", number)) => Ok(Fold::Y(util::parse_value::<usize>(number, line_number, 14)?)),
+        _ => Err(Error::parse(line_number, 12, format!("expected 'x=' or 'y=', found '{}'", instruction))),
+    };
+}
+
+pub fn get_paper_size_from_instructions(instructions: &Vec<Fold>) -> Result<(usize, usize), Error> {
+    if instructions.len() < 2 {
+        return Err(Error::input("at least two fold instructions are needed to know the size of the paper"));
     }
+
+    return match (instructions[0], instructions[1]) {
+        (Fold::X(number1), Fold::Y(number2)) => Ok((number1 * 2 + 1, number2 * 2 + 1)),
+        (Fold::Y(number1), Fold::X(number2)) => Ok((number2 * 2 + 1, number1 * 2 + 1)),
+        _ => Err(Error::input("the first two folds have to be along different axes to know the size of the paper")),
+    };
 }
 
 #[test]
 fn test_day13_part1_example() {
-    let input = String::from("6,10
-0,14
-9,10
-0,3
-10,4
-4,11
-6,0
-6,12
-4,1
-0,13
-10,12
-3,4
-3,0
-8,4
-1,10
-2,14
-8,10
-9,0
-
-fold along y=7
-fold along x=5");
-    let lines = util::split_string_by_string(&input, "\n");
-    let (paper, instructions) = extract_paper_and_instructions(&lines);
+    let input = util::read_input_file("examples/day13/example.txt").unwrap();
+    let (paper, instructions) = extract_paper_and_instructions(&input).unwrap();
 
     let folded_paper = fold_paper(&paper, &instructions[0]);
-    let sum: usize = folded_paper.iter().map(|y| y.iter().filter(|x| **x == true).count()).sum();
+    let sum: usize = count_dots(&folded_paper);
     assert_eq!(sum, 17);
 
     let folded_paper2 = fold_paper(&folded_paper, &instructions[1]);
-    let sum2: usize = folded_paper2.iter().map(|y| y.iter().filter(|x| **x == true).count()).sum();
+    let sum2: usize = count_dots(&folded_paper2);
     assert_eq!(sum2, 16);
 }
 
 #[test]
 fn test_day13_part1_solution() {
-    let input = util::read_input_file("day13.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let (paper, instructions) = extract_paper_and_instructions(&lines);
+    let input = util::read_input_file("day13/input.txt").unwrap();
+    let (paper, instructions) = extract_paper_and_instructions(&input).unwrap();
 
     let folded_paper = fold_paper(&paper, &instructions[0]);
-    let sum: usize = folded_paper.iter().map(|y| y.iter().filter(|x| **x == true).count()).sum();
+    let sum: usize = count_dots(&folded_paper);
     assert_eq!(sum, 687);
 }
 
 #[test]
 fn test_day13_part2_solution() {
-    let input = util::read_input_file("day13.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let (mut paper, instructions) = extract_paper_and_instructions(&lines);
+    let input = util::read_input_file("day13/input.txt").unwrap();
+    let (mut paper, instructions) = extract_paper_and_instructions(&input).unwrap();
 
     for i in 0..instructions.len() {
         paper = fold_paper(&paper, &instructions[i]);
     }
 
-    let sum: usize = paper.iter().map(|y| y.iter().filter(|x| **x == true).count()).sum();
+    let sum: usize = count_dots(&paper);
     assert_eq!(sum, 98);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_day13_check_reports_all_problems() {
+    let input = "6,10\n11,3\n1,x\n\nfold along y=7\nfold along x=5\nfold along y=2\n";
+    let error = Day13::check(input).unwrap_err();
+    let problems: Vec<String> = error.problems().iter().map(|problem| problem.to_string()).collect();
+
+    assert_eq!(problems, vec![
+        "line 2, column 1: dot 11,3 is outside of the 11x15 paper",
+        "line 3, column 3: invalid number 'x'",
+        "line 7, column 14: fold at 2 is not in the middle of the paper with size 7",
+    ]);
+}
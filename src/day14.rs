@@ -2,34 +2,63 @@ use std::collections::HashMap;
 use substring::Substring;
 use std::cmp;
 
-mod util;
+use crate::error::Error;
+#[cfg(test)]
+use crate::generate;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main() {
-    let input = util::read_input_file("day14.txt");
-    let mut lines = util::split_string_by_string(&input, "\n");
-    let polymers = lines.remove(0);
-    let rules = parse_insertion_rules(&lines);
+pub struct Day14;
 
-    let (min, max) = perform_polymerization(&polymers, &rules, 10);
-    println!("Part 1: Solution={}", max - min);
+impl Solution for Day14 {
+    type Input = (String, Vec<(String, String)>);
 
-    let (min, max) = perform_polymerization(&polymers, &rules, 40);
-    println!("Part 2: Solution={}", max - min);
+    fn parse(input: &str) -> Result<(String, Vec<(String, String)>), Error> {
+        return parse_polymer_instructions(input);
+    }
+
+    fn part1((polymers, rules): &(String, Vec<(String, String)>)) -> Result<Answer, Error> {
+        let (min, max) = perform_polymerization(polymers, rules, 10);
+        return Ok((max - min).into());
+    }
+
+    fn part2((polymers, rules): &(String, Vec<(String, String)>)) -> Result<Answer, Error> {
+        let (min, max) = perform_polymerization(polymers, rules, 40);
+        return Ok((max - min).into());
+    }
 }
 
-fn parse_insertion_rules(lines: &Vec<String>) -> Vec<(&str, &str)> {
-    let mut rules: Vec<(&str, &str)> = vec![];
+pub fn parse_polymer_instructions(input: &str) -> Result<(String, Vec<(String, String)>), Error> {
+    let mut lines = util::numbered_lines(input);
+    if lines.is_empty() {
+        return Err(Error::input("missing the polymer template"));
+    }
+
+    let (line_number, polymers) = lines.remove(0);
+    let template = match polymers.find(|c: char| !c.is_ascii_uppercase()) {
+        Some(column) => Err(Error::parse(line_number, column + 1, "the polymer template must only contain uppercase letters")),
+        None => Ok(polymers.to_string()),
+    };
+
+    return match (template, parse_insertion_rules(&lines)) {
+        (Ok(template), Ok(rules)) => Ok((template, rules)),
+        (template, rules) => Err(Error::combine(template.err().into_iter().chain(rules.err()).collect())),
+    };
+}
 
-    for line in lines {
+pub fn parse_insertion_rules(lines: &[(usize, &str)]) -> Result<Vec<(String, String)>, Error> {
+    return util::collect_results(lines.iter().map(|(line_number, line)| {
         let parts: Vec<&str> = line.split(" -> ").collect();
-        assert_eq!(parts.len(), 2);
-        rules.push((parts[0], parts[1]));
-    }
+        let is_valid = parts.len() == 2 && parts[0].len() == 2 && parts[1].len() == 1 && line.chars().filter(|c| c.is_ascii_uppercase()).count() == 3;
+        if !is_valid {
+            return Err(Error::parse(*line_number, 1, format!("expected an insertion rule 'AB -> C', found '{}'", line)));
+        }
 
-    return rules;
+        return Ok((parts[0].to_string(), parts[1].to_string()));
+    }));
 }
 
-fn perform_polymerization(polymers: &String, rules: &Vec<(&str, &str)>, steps: i32) -> (u64, u64) {
+pub fn perform_polymerization(polymers: &String, rules: &Vec<(String, String)>, steps: i32) -> (u64, u64) {
     let mut pairs: HashMap<String, u64> = HashMap::new();
 
     for i in 0..polymers.len() {
@@ -49,9 +78,9 @@ fn perform_polymerization(polymers: &String, rules: &Vec<(&str, &str)>, steps: i
         for pair in step_pairs.keys() {
             match rules.iter().find(|x| &x.0 == pair) {
                 Some(rule) => {
-                    let mut pair1 = String::from(pair.chars().nth(0).unwrap());
-                    pair1 += rule.1;
-                    let mut pair2 = String::from(rule.1);
+                    let mut pair1 = String::from(pair.chars().next().unwrap());
+                    pair1 += &rule.1;
+                    let mut pair2 = rule.1.clone();
                     pair2.push(pair.chars().nth(1).unwrap());
 
                     if !pairs.contains_key(&pair1) {
@@ -79,7 +108,7 @@ fn perform_polymerization(polymers: &String, rules: &Vec<(&str, &str)>, steps: i
 
     let mut polymer_count: HashMap<char, u64> = HashMap::new();
     for pair in pairs {
-        let polymer = pair.0.chars().nth(0).unwrap();
+        let polymer = pair.0.chars().next().unwrap();
 
         if !polymer_count.contains_key(&polymer) {
             polymer_count.insert(polymer, pair.1);
@@ -94,29 +123,34 @@ fn perform_polymerization(polymers: &String, rules: &Vec<(&str, &str)>, steps: i
     return (*min, *max);
 }
 
+// Builds the polymer itself instead of counting its pairs, only feasible for a few steps
+pub fn build_polymer(polymers: &String, rules: &Vec<(String, String)>, steps: i32) -> (u64, u64) {
+    let insertions: HashMap<&str, char> = rules.iter().map(|(pair, element)| (pair.as_str(), element.chars().next().unwrap())).collect();
+    let mut polymer: Vec<char> = polymers.chars().collect();
+
+    for _ in 0..steps {
+        let mut next_polymer: Vec<char> = vec![polymer[0]];
+        for pair in polymer.windows(2) {
+            if let Some(element) = insertions.get(pair.iter().collect::<String>().as_str()) {
+                next_polymer.push(*element);
+            }
+            next_polymer.push(pair[1]);
+        }
+        polymer = next_polymer;
+    }
+
+    let mut counts: HashMap<char, u64> = HashMap::new();
+    for element in polymer {
+        *counts.entry(element).or_insert(0) += 1;
+    }
+
+    return (*counts.values().min().unwrap(), *counts.values().max().unwrap());
+}
+
 #[test]
 fn test_day14_part1_example() {
-    let input = String::from("NNCB
-
-CH -> B
-HH -> N
-CB -> H
-NH -> C
-HB -> C
-HC -> B
-HN -> C
-NN -> C
-BH -> H
-NC -> B
-NB -> B
-BN -> B
-BB -> N
-BC -> B
-CC -> N
-CN -> C");
-    let mut lines = util::split_string_by_string(&input, "\n");
-    let polymers = lines.remove(0);
-    let rules = parse_insertion_rules(&lines);
+    let input = util::read_input_file("examples/day14/example.txt").unwrap();
+    let (polymers, rules) = parse_polymer_instructions(&input).unwrap();
     let (min, max) = perform_polymerization(&polymers, &rules, 4);
 
     assert_eq!(min, 5);
@@ -125,10 +159,8 @@ CN -> C");
 
 #[test]
 fn test_day14_part1_solution() {
-    let input = util::read_input_file("day14.txt");
-    let mut lines = util::split_string_by_string(&input, "\n");
-    let polymers = lines.remove(0);
-    let rules = parse_insertion_rules(&lines);
+    let input = util::read_input_file("day14/input.txt").unwrap();
+    let (polymers, rules) = parse_polymer_instructions(&input).unwrap();
     let (min, max) = perform_polymerization(&polymers, &rules, 10);
     
     assert_eq!(min, 649);
@@ -137,12 +169,29 @@ fn test_day14_part1_solution() {
 
 #[test]
 fn test_day14_part2_solution() {
-    let input = util::read_input_file("day14.txt");
-    let mut lines = util::split_string_by_string(&input, "\n");
-    let polymers = lines.remove(0);
-    let rules = parse_insertion_rules(&lines);
+    let input = util::read_input_file("day14/input.txt").unwrap();
+    let (polymers, rules) = parse_polymer_instructions(&input).unwrap();
     let (min, max) = perform_polymerization(&polymers, &rules, 40);
     
     assert_eq!(min, 362729313279);
     assert_eq!(max, 6088469227561);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_day14_part1_reference() {
+    let input = util::read_input_file("examples/day14/example.txt").unwrap();
+    let (polymers, rules) = parse_polymer_instructions(&input).unwrap();
+    assert_eq!(build_polymer(&polymers, &rules, 4), (5, 23));
+
+    let solve = |input: &str| {
+        let (polymers, rules) = parse_polymer_instructions(input).unwrap();
+        return perform_polymerization(&polymers, &rules, 10);
+    };
+    let reference = |input: &str| {
+        let (polymers, rules) = parse_polymer_instructions(input).unwrap();
+        return build_polymer(&polymers, &rules, 10);
+    };
+
+    let counterexample = generate::find_counterexample(14, &[1, 2, 5, 10], 10, solve, reference);
+    assert!(counterexample.is_none(), "{:?}", counterexample);
+}
@@ -1,173 +1,126 @@
-mod util;
-
-#[derive(Clone, Copy, Debug)]
-struct Position {
-    x: usize,
-    y: usize,
-}
-
-trait NodeLogic {
-    fn get_neighbors(&mut self, row_size: usize) -> Vec<usize>;
-    fn update_cost(&mut self, new_cost: i32, new_predecessor: usize);
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+#[cfg(test)]
+use crate::util;
+use crate::util::Grid;
+
+pub trait NodeLogic {
+    fn get_neighbors(&self, nodes: &Grid<Node>) -> Vec<(usize, usize)>;
+    fn update_cost(&mut self, new_cost: i32, new_predecessor: (usize, usize));
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Node {
-    index: usize,
-    enter_cost: i32,
-    cost: i32,
-    predecessor: usize,
+pub struct Node {
+    pub position: (usize, usize),
+    pub enter_cost: i32,
+    pub cost: i32,
+    pub predecessor: Option<(usize, usize)>,
 }
 
 impl NodeLogic for Node {
-    fn get_neighbors(&mut self, row_size: usize) -> Vec<usize> {
-        let mut neighbors = vec![];
-
-        if (self.index + 1) % row_size > 0 && self.predecessor != self.index + 1 {
-            neighbors.push(self.index + 1); // right
-        }
-
-        if self.index % row_size > 0 && self.predecessor != self.index - 1 {
-            neighbors.push(self.index - 1); // left
-        }
-
-        if self.index >= row_size && self.predecessor != self.index - row_size {
-            neighbors.push(self.index - row_size); // up
-        }
-
-        if self.index + row_size < row_size * row_size && self.predecessor != self.index + row_size {
-            neighbors.push(self.index + row_size); // down
-        }
-
-        return neighbors;
+    fn get_neighbors(&self, nodes: &Grid<Node>) -> Vec<(usize, usize)> {
+        let (x, y) = self.position;
+        return nodes.neighbours4(x, y).filter(|neighbor| Some(*neighbor) != self.predecessor).collect();
     }
 
-    fn update_cost(&mut self, new_cost: i32, new_predecessor: usize) {
+    fn update_cost(&mut self, new_cost: i32, new_predecessor: (usize, usize)) {
         self.cost = new_cost;
-        self.predecessor = new_predecessor;
+        self.predecessor = Some(new_predecessor);
     }
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Candidate {
-    index: usize,
-    cost: i32,
+pub struct Candidate {
+    pub position: (usize, usize),
+    pub cost: i32,
 }
 
-fn main() {
-    let input = util::read_input_file("day15.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let cave = parse_cave(&lines);
+pub struct Day15;
 
-    let mut nodes = build_nodes(&cave);
-    let cost = find_path_with_lowest_cost(&mut nodes);
-    println!("Part 1: Solution={}", cost);
+impl Solution for Day15 {
+    type Input = Grid<i32>;
 
-    let extended_cave = extend_cave(&cave, 5);
-    let mut nodes = build_nodes(&extended_cave);
-    let cost = find_path_with_lowest_cost(&mut nodes);
-    println!("Part 2: Solution={}", cost);
-}
+    fn parse(input: &str) -> Result<Grid<i32>, Error> {
+        return parse_cave(input);
+    }
 
-fn parse_cave(lines: &Vec<String>) -> Vec<Vec<i32>> {
-    let mut cave: Vec<Vec<i32>> = vec![];
+    fn part1(cave: &Grid<i32>) -> Result<Answer, Error> {
+        let mut nodes = build_nodes(cave);
+        return Ok(find_path_with_lowest_cost(&mut nodes).into());
+    }
 
-    for line in lines {
-        let line = line.chars().map(|x| x.to_digit(10).unwrap() as i32).collect();
-        cave.push(line);
+    fn part2(cave: &Grid<i32>) -> Result<Answer, Error> {
+        let extended_cave = extend_cave(cave, 5);
+        let mut nodes = build_nodes(&extended_cave);
+        return Ok(find_path_with_lowest_cost(&mut nodes).into());
     }
+}
 
-    return cave;
+pub fn parse_cave(input: &str) -> Result<Grid<i32>, Error> {
+    return Grid::parse_digits(input);
 }
 
-fn extend_cave(cave: &Vec<Vec<i32>>, size_factor: usize) -> Vec<Vec<i32>> {
-    let original_size = cave.len();
-    let new_size = original_size * size_factor;
-    let mut extended_cave = vec![vec![0; new_size]; new_size];
-
-    for step_y in 0..size_factor {
-        for step_x in 0..size_factor {
-            for y in 0..cave.len() {
-                for x in 0..cave.len() {
-                    let new_value = (cave[y][x] + step_y as i32 + step_x as i32 - 1) % 9 + 1;
-                    extended_cave[y + step_y * original_size][x + step_x * original_size] = new_value;
-                }
-            }
-        }
-    }
+pub fn extend_cave(cave: &Grid<i32>, size_factor: usize) -> Grid<i32> {
+    let (width, height) = (cave.width(), cave.height());
 
-    return extended_cave;
+    return Grid::from_fn(width * size_factor, height * size_factor, |x, y| {
+        let step = (x / width + y / height) as i32;
+        return (cave[(x % width, y % height)] + step - 1) % 9 + 1;
+    });
 }
 
-fn find_path_with_lowest_cost(nodes: &mut Vec<Node>) -> i32 {
+pub fn find_path_with_lowest_cost(nodes: &mut Grid<Node>) -> i32 {
     // Add start node to queue
-    let mut queue = vec![Candidate { index: 0, cost: 0 }];
-    let row_size = (nodes.len() as f64).sqrt() as usize;
+    let mut queue = vec![Candidate { position: (0, 0), cost: 0 }];
 
-    while queue.len() > 0 {
-        dijkstra_step(nodes, &mut queue, row_size);
+    while !queue.is_empty() {
+        dijkstra_step(nodes, &mut queue);
     }
 
     // Lowest cost is stored in target node
-    return nodes[row_size * row_size - 1].cost;
+    return nodes[(nodes.width() - 1, nodes.height() - 1)].cost;
 }
 
-fn dijkstra_step(nodes: &mut Vec<Node>, queue: &mut Vec<Candidate>, row_size: usize) {
-    queue.sort_by(|x, y| x.cost.cmp(&y.cost));
+pub fn dijkstra_step(nodes: &mut Grid<Node>, queue: &mut Vec<Candidate>) {
+    queue.sort_by_key(|x| x.cost);
 
-    let mut current_node = nodes[queue[0].index];
+    let current_node = nodes[queue[0].position];
 
-    if current_node.index == row_size * row_size - 1 {
+    if current_node.position == (nodes.width() - 1, nodes.height() - 1) {
         queue.clear();
         return;
     }
 
-    let neighbors = current_node.get_neighbors(row_size);
+    let neighbors = current_node.get_neighbors(nodes);
 
-    for neighbor_index in neighbors {
-        let neighbor_node = &mut nodes[neighbor_index];
+    for neighbor_position in neighbors {
+        let neighbor_node = &mut nodes[neighbor_position];
         let new_cost = current_node.cost + neighbor_node.enter_cost;
         if new_cost < neighbor_node.cost {
-            neighbor_node.update_cost(new_cost, current_node.index);
-            queue.push(Candidate { index: neighbor_index, cost: new_cost });
+            neighbor_node.update_cost(new_cost, current_node.position);
+            queue.push(Candidate { position: neighbor_position, cost: new_cost });
         }
     }
 
     queue.remove(0);
 }
 
-fn build_nodes(cave: &Vec<Vec<i32>>) -> Vec<Node> {
-    let mut nodes = vec![];
-
-    let size = cave.len();
-    for y in 0..size {
-        for x in 0..size {
-            // predecessor > size*size-1 means that we have no predecessor because it would be out of bounds
-            nodes.push(Node { index: nodes.len(), enter_cost: cave[y][x], cost: i32::MAX, predecessor: size*size });
-        }
-    }
+pub fn build_nodes(cave: &Grid<i32>) -> Grid<Node> {
+    let mut nodes = Grid::from_fn(cave.width(), cave.height(), |x, y| {
+        Node { position: (x, y), enter_cost: cave[(x, y)], cost: i32::MAX, predecessor: None }
+    });
 
     // Special case: start node
-    nodes[0].enter_cost = 0;
-    nodes[0].cost = 0;
+    nodes[(0, 0)].enter_cost = 0;
+    nodes[(0, 0)].cost = 0;
 
     return nodes;
 }
 
 #[test]
 fn test_day15_part1_example() {
-    let input = String::from("1163751742
-1381373672
-2136511328
-3694931569
-7463417111
-1319128137
-1359912421
-3125421639
-1293138521
-2311944581");
-    let lines = util::split_string_by_string(&input, "\n");
-    let cave = parse_cave(&lines);
+    let input = util::read_input_file("examples/day15/example.txt").unwrap();
+    let cave = parse_cave(&input).unwrap();
     let mut nodes = build_nodes(&cave);
     let cost = find_path_with_lowest_cost(&mut nodes);
 
@@ -176,9 +129,8 @@ fn test_day15_part1_example() {
 
 #[test]
 fn test_day15_part1_solution() {
-    let input = util::read_input_file("day15.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let cave = parse_cave(&lines);
+    let input = util::read_input_file("day15/input.txt").unwrap();
+    let cave = parse_cave(&input).unwrap();
     let mut nodes = build_nodes(&cave);
     let cost = find_path_with_lowest_cost(&mut nodes);
 
@@ -187,18 +139,8 @@ fn test_day15_part1_solution() {
 
 #[test]
 fn test_day15_part2_example() {
-    let input = String::from("1163751742
-1381373672
-2136511328
-3694931569
-7463417111
-1319128137
-1359912421
-3125421639
-1293138521
-2311944581");
-    let lines = util::split_string_by_string(&input, "\n");
-    let cave = parse_cave(&lines);
+    let input = util::read_input_file("examples/day15/example.txt").unwrap();
+    let cave = parse_cave(&input).unwrap();
     let extended_cave = extend_cave(&cave, 5);
     let mut nodes = build_nodes(&extended_cave);
     let cost = find_path_with_lowest_cost(&mut nodes);
@@ -208,12 +150,19 @@ fn test_day15_part2_example() {
 
 #[test]
 fn test_day15_part2_solution() {
-    let input = util::read_input_file("day15.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let cave = parse_cave(&lines);
+    let input = util::read_input_file("day15/input.txt").unwrap();
+    let cave = parse_cave(&input).unwrap();
     let extended_cave = extend_cave(&cave, 5);
     let mut nodes = build_nodes(&extended_cave);
     let cost = find_path_with_lowest_cost(&mut nodes);
 
     assert_eq!(cost, 2864);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_day15_non_square_cave() {
+    let cave = parse_cave("19\n11\n91").unwrap();
+    let mut nodes = build_nodes(&cave);
+    let cost = find_path_with_lowest_cost(&mut nodes);
+    assert_eq!(cost, 3);
+}
@@ -1,21 +1,30 @@
 use bitbuffer::{BitReadBuffer, BigEndian, BitReadStream};
 use substring::Substring;
 
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main() {
-    let input = util::read_input_file("day16.txt").trim().to_string();
-    let packet = parse_packet(&input);
+pub struct Day16;
 
-    let version_sum = sum_packet_versions(&packet);
-    println!("Part 1: Solution={}", version_sum);
+impl Solution for Day16 {
+    type Input = Packet;
 
-    let operator_result = execute_packet_operators(&packet);
-    println!("Part 2: Solution={}", operator_result);
+    fn parse(input: &str) -> Result<Packet, Error> {
+        return parse_packet(input.trim());
+    }
+
+    fn part1(packet: &Packet) -> Result<Answer, Error> {
+        return Ok(sum_packet_versions(packet).into());
+    }
+
+    fn part2(packet: &Packet) -> Result<Answer, Error> {
+        return Ok(execute_packet_operators(packet).into());
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum Operator {
+pub enum Operator {
     Sum,
     Product,
     Minimum,
@@ -27,15 +36,15 @@ enum Operator {
 }
 
 #[derive(Clone, Debug)]
-struct Packet {
-    version: u8,
-    operator: Operator,
-    sub_packets: Vec<Packet>,
-    literal_value: u64,
+pub struct Packet {
+    pub version: u8,
+    pub operator: Operator,
+    pub sub_packets: Vec<Packet>,
+    pub literal_value: u64,
 }
 
-fn hex_to_binary(c: char) -> u8 {
-    return match c {
+pub fn hex_to_binary(c: char, column: usize) -> Result<u8, Error> {
+    return Ok(match c {
         '0' => 0b0000,
         '1' => 0b0001,
         '2' => 0b0010,
@@ -52,11 +61,11 @@ fn hex_to_binary(c: char) -> u8 {
         'D' => 0b1101,
         'E' => 0b1110,
         'F' => 0b1111,
-        _ => panic!("illegal character {}", c),
-    }
+        _ => return Err(Error::parse(1, column, format!("expected a hexadecimal digit, found '{}'", c))),
+    });
 }
 
-fn type_id_to_operator(type_id: u8) -> Operator {
+pub fn type_id_to_operator(type_id: u8) -> Operator {
     return match type_id {
         0 => Operator::Sum,
         1 => Operator::Product,
@@ -70,29 +79,32 @@ fn type_id_to_operator(type_id: u8) -> Operator {
     }
 }
 
-#[allow(unused_must_use)]
-fn read_next_packet(stream: &mut BitReadStream<BigEndian>) -> Packet {
+fn truncated_packet() -> Error {
+    return Error::input("the transmission ends in the middle of a packet");
+}
+
+pub fn read_next_packet(stream: &mut BitReadStream<BigEndian>) -> Result<Packet, Error> {
     let mut sub_packets = vec![];
     let mut literal_value: u64 = 0;
 
-    let packet_version = stream.read_int::<u8>(3).unwrap();
-    let packet_type = stream.read_int::<u8>(3).unwrap();
+    let packet_version = stream.read_int::<u8>(3).map_err(|_| truncated_packet())?;
+    let packet_type = stream.read_int::<u8>(3).map_err(|_| truncated_packet())?;
     let operator = type_id_to_operator(packet_type);
 
     if operator != Operator::Literal {
-        let length_type_id = stream.read_int::<u8>(1).unwrap();
+        let length_type_id = stream.read_int::<u8>(1).map_err(|_| truncated_packet())?;
 
         if length_type_id == 0 {
-            let total_length = stream.read_int::<usize>(15).unwrap();
+            let total_length = stream.read_int::<usize>(15).map_err(|_| truncated_packet())?;
             let start_pos = stream.pos();
             while stream.pos() < start_pos + total_length {
-                let sub_packet = read_next_packet(stream);
+                let sub_packet = read_next_packet(stream)?;
                 sub_packets.push(sub_packet);
             }
         } else {
-            let num_sub_packets = stream.read_int::<usize>(11).unwrap();
+            let num_sub_packets = stream.read_int::<usize>(11).map_err(|_| truncated_packet())?;
             for _ in 0..num_sub_packets {
-                let sub_packet = read_next_packet(stream);
+                let sub_packet = read_next_packet(stream)?;
                 sub_packets.push(sub_packet);
             }
         }
@@ -104,13 +116,13 @@ fn read_next_packet(stream: &mut BitReadStream<BigEndian>) -> Packet {
                 let pos = stream.pos();
                 if pos % 4 > 0 {
                     // Align to the next 4-bit block
-                    stream.set_pos(pos + 4 - pos % 4);
+                    stream.set_pos(pos + 4 - pos % 4).map_err(|_| truncated_packet())?;
                 }
                 break;
             }
 
-            let is_last_group = stream.read_int::<u8>(1).unwrap() == 0;
-            let value = stream.read_int::<u8>(4).unwrap();
+            let is_last_group = stream.read_int::<u8>(1).map_err(|_| truncated_packet())? == 0;
+            let value = stream.read_int::<u8>(4).map_err(|_| truncated_packet())?;
             let value_string = &format!("{:#06b}", value);
             literal_string += value_string.substring(2, value_string.len());
 
@@ -119,15 +131,31 @@ fn read_next_packet(stream: &mut BitReadStream<BigEndian>) -> Packet {
             }
         }
 
-        literal_value = u64::from_str_radix(&literal_string, 2).unwrap();
+        if literal_string.is_empty() {
+            return Err(truncated_packet());
+        }
+
+        literal_value = u64::from_str_radix(&literal_string, 2).map_err(|_| Error::input("a literal value does not fit into 64 bits"))?;
     }
 
-    return Packet { version: packet_version, operator: operator, sub_packets: sub_packets, literal_value: literal_value };
+    // The evaluation expects comparisons to have two operands and all other operators at least one
+    let expected_sub_packets = match operator {
+        Operator::Literal => None,
+        Operator::GreaterThan | Operator::LessThan | Operator::EqualTo => Some(sub_packets.len() == 2),
+        _ => Some(!sub_packets.is_empty()),
+    };
+    if expected_sub_packets == Some(false) {
+        return Err(Error::input(format!("the {:?} packet has an invalid number of sub packets: {}", operator, sub_packets.len())));
+    }
+
+    return Ok(Packet { version: packet_version, operator: operator, sub_packets: sub_packets, literal_value: literal_value });
 }
 
-fn parse_packet(line: &String) -> Packet {
-    let binary: Vec<u8> = line.chars().map(hex_to_binary).collect();
-    assert_eq!(binary.len() % 2, 0);
+pub fn parse_packet(line: &str) -> Result<Packet, Error> {
+    let binary: Vec<u8> = util::collect_results(line.chars().enumerate().map(|(i, c)| hex_to_binary(c, i + 1)))?;
+    if binary.is_empty() || !binary.len().is_multiple_of(2) {
+        return Err(Error::input(format!("expected an even number of hexadecimal digits, found {}", binary.len())));
+    }
 
     let mut bytes: Vec<u8> = vec![];
     for i in (0..binary.len()).step_by(2) {
@@ -137,12 +165,10 @@ fn parse_packet(line: &String) -> Packet {
     let buffer = BitReadBuffer::new(&bytes, BigEndian);
     let mut stream = BitReadStream::new(buffer);
 
-    let packet = read_next_packet(&mut stream);
-
-    return packet;
+    return read_next_packet(&mut stream);
 }
 
-fn sum_packet_versions(packet: &Packet) -> u64 {
+pub fn sum_packet_versions(packet: &Packet) -> u64 {
     let mut sum = packet.version as u64;
 
     for sub_packet in &packet.sub_packets {
@@ -152,7 +178,7 @@ fn sum_packet_versions(packet: &Packet) -> u64 {
     return sum;
 }
 
-fn execute_packet_operators(packet: &Packet) -> u64 {
+pub fn execute_packet_operators(packet: &Packet) -> u64 {
     if packet.operator == Operator::Literal {
         return packet.literal_value;
     }
@@ -173,8 +199,8 @@ fn execute_packet_operators(packet: &Packet) -> u64 {
 
 #[test]
 fn test_day16_part1_example1() {
-    let input = String::from("8A004A801A8002F478");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example1.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let version_sum = sum_packet_versions(&packet);
 
     assert_eq!(version_sum, 16);
@@ -182,8 +208,8 @@ fn test_day16_part1_example1() {
 
 #[test]
 fn test_day16_part1_example2() {
-    let input = String::from("620080001611562C8802118E34");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example2.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let version_sum = sum_packet_versions(&packet);
 
     assert_eq!(version_sum, 12);
@@ -191,8 +217,8 @@ fn test_day16_part1_example2() {
 
 #[test]
 fn test_day16_part1_example3() {
-    let input = String::from("C0015000016115A2E0802F182340");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example3.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let version_sum = sum_packet_versions(&packet);
 
     assert_eq!(version_sum, 23);
@@ -200,8 +226,8 @@ fn test_day16_part1_example3() {
 
 #[test]
 fn test_day16_part1_example4() {
-    let input = String::from("A0016C880162017C3686B18A3D4780");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example4.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let version_sum = sum_packet_versions(&packet);
 
     assert_eq!(version_sum, 31);
@@ -209,8 +235,8 @@ fn test_day16_part1_example4() {
 
 #[test]
 fn test_day16_part1_solution() {
-    let input = util::read_input_file("day16.txt").trim().to_string();
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("day16/input.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let version_sum = sum_packet_versions(&packet);
 
     assert_eq!(version_sum, 923);
@@ -218,8 +244,8 @@ fn test_day16_part1_solution() {
 
 #[test]
 fn test_day16_part2_example1() {
-    let input = String::from("C200B40A82");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example5.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 3);
@@ -227,8 +253,8 @@ fn test_day16_part2_example1() {
 
 #[test]
 fn test_day16_part2_example2() {
-    let input = String::from("04005AC33890");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example6.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 54);
@@ -236,8 +262,8 @@ fn test_day16_part2_example2() {
 
 #[test]
 fn test_day16_part2_example3() {
-    let input = String::from("880086C3E88112");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example7.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 7);
@@ -245,8 +271,8 @@ fn test_day16_part2_example3() {
 
 #[test]
 fn test_day16_part2_example4() {
-    let input = String::from("CE00C43D881120");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example8.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 9);
@@ -254,8 +280,8 @@ fn test_day16_part2_example4() {
 
 #[test]
 fn test_day16_part2_example5() {
-    let input = String::from("D8005AC2A8F0");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example9.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 1);
@@ -263,8 +289,8 @@ fn test_day16_part2_example5() {
 
 #[test]
 fn test_day16_part2_example6() {
-    let input = String::from("F600BC2D8F");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example10.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 0);
@@ -272,8 +298,8 @@ fn test_day16_part2_example6() {
 
 #[test]
 fn test_day16_part2_example7() {
-    let input = String::from("9C005AC2F8F0");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example11.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 0);
@@ -281,8 +307,8 @@ fn test_day16_part2_example7() {
 
 #[test]
 fn test_day16_part2_example8() {
-    let input = String::from("9C0141080250320F1802104A08");
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("examples/day16/example12.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 1);
@@ -290,8 +316,8 @@ fn test_day16_part2_example8() {
 
 #[test]
 fn test_day16_part2_solution() {
-    let input = util::read_input_file("day16.txt").trim().to_string();
-    let packet = parse_packet(&input);
+    let input = util::read_input_file("day16/input.txt").unwrap().trim().to_string();
+    let packet = parse_packet(&input).unwrap();
     let operator_result = execute_packet_operators(&packet);
 
     assert_eq!(operator_result, 258888628940);
@@ -1,32 +1,45 @@
 use std::cmp;
-use substring::Substring;
 
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main() {
-    let input = util::read_input_file("day17.txt");
-    let target_area = parse_target_area(&input);
-    let (y_max, hit_count) = find_initial_velocity(&target_area);
-    println!("Part 1: Solution={}", y_max);
-    println!("Part 2: Solution={}", hit_count);
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Area;
+
+    fn parse(input: &str) -> Result<Area, Error> {
+        return parse_target_area(input);
+    }
+
+    fn part1(target_area: &Area) -> Result<Answer, Error> {
+        let (y_max, _) = find_initial_velocity(target_area);
+        return Ok(y_max.into());
+    }
+
+    fn part2(target_area: &Area) -> Result<Answer, Error> {
+        let (_, hit_count) = find_initial_velocity(target_area);
+        return Ok(hit_count.into());
+    }
 }
 
 
 #[derive(Debug, Clone, Copy)]
-struct Area {
-    x_min: i32,
-    x_max: i32,
-    y_min: i32,
-    y_max: i32,
+pub struct Area {
+    pub x_min: i32,
+    pub x_max: i32,
+    pub y_min: i32,
+    pub y_max: i32,
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Velocity {
-    x: i32,
-    y: i32,
+pub struct Velocity {
+    pub x: i32,
+    pub y: i32,
 }
 
-fn find_initial_velocity(target_area: &Area) -> (i32, i32) {
+pub fn find_initial_velocity(target_area: &Area) -> (i32, i32) {
     let mut hit_count = 0;
     let mut global_y_max = 0;
 
@@ -44,7 +57,7 @@ fn find_initial_velocity(target_area: &Area) -> (i32, i32) {
     return (global_y_max, hit_count);
 }
 
-fn hits_target_area(velocity: &mut Velocity, target_area: &Area) -> (bool, i32) {
+pub fn hits_target_area(velocity: &mut Velocity, target_area: &Area) -> (bool, i32) {
     let mut x = 0;
     let mut y = 0;
     let mut y_max = 0;
@@ -68,43 +81,77 @@ fn hits_target_area(velocity: &mut Velocity, target_area: &Area) -> (bool, i32)
     }
 }
 
-fn parse_target_area(line: &String) -> Area {
-    let line = line.substring(13, line.len());
-    let xy: Vec<&str> = line.split(", ").collect();
-    assert_eq!(xy.len(), 2);
-    let x_range: Vec<i32> = xy[0].trim().substring(2, xy[0].len()).split("..").map(|x| x.parse::<i32>().unwrap()).collect();
-    let y_range: Vec<i32> = xy[1].trim().substring(2, xy[1].len()).split("..").map(|x| x.parse::<i32>().unwrap()).collect();
-    return Area { x_min: x_range[0], x_max: x_range[1], y_min: y_range[0], y_max: y_range[1] };
+pub fn parse_target_area(line: &str) -> Result<Area, Error> {
+    let prefix = "target area: ";
+    let line = line.trim_end();
+    if !line.starts_with(prefix) {
+        return Err(Error::parse(1, 1, format!("expected '{}x=<min>..<max>, y=<min>..<max>'", prefix)));
+    }
+
+    let ranges = util::split_with_columns(&line[prefix.len()..], ",");
+    if ranges.len() != 2 {
+        return Err(Error::parse(1, prefix.len() + 1, format!("expected an x and a y range, found '{}'", &line[prefix.len()..])));
+    }
+
+    let (x_min, x_max) = parse_range(ranges[0].1, "x=", 1, prefix.len() + ranges[0].0)?;
+    let (y_min, y_max) = parse_range(ranges[1].1, "y=", 1, prefix.len() + ranges[1].0)?;
+
+    // The velocity search only shoots forward and expects the target area to be below the submarine
+    if x_min < 0 || y_max >= 0 {
+        return Err(Error::input(format!("the target area must have x >= 0 and y < 0, found x={}..{}, y={}..{}", x_min, x_max, y_min, y_max)));
+    }
+
+    return Ok(Area { x_min, x_max, y_min, y_max });
+}
+
+// Parses a range like "x=20..30" that starts at the given line and column
+pub fn parse_range(text: &str, name: &str, line_number: usize, column: usize) -> Result<(i32, i32), Error> {
+    let range = text.strip_prefix(name).ok_or_else(|| Error::parse(line_number, column, format!("expected a range starting with '{}', found '{}'", name, text)))?;
+    let (min, max) = range.split_once("..").ok_or_else(|| Error::parse(line_number, column + name.len(), format!("expected '<min>..<max>', found '{}'", range)))?;
+
+    let min_value = util::parse_value::<i32>(min, line_number, column + name.len())?;
+    let max_value = util::parse_value::<i32>(max, line_number, column + name.len() + min.len() + 2)?;
+    if min_value > max_value {
+        return Err(Error::parse(line_number, column, format!("the range {}{} is empty", name, range)));
+    }
+
+    return Ok((min_value, max_value));
 }
 
 #[test]
 fn test_day17_part1_example() {
-    let input = String::from("target area: x=20..30, y=-10..-5");
-    let target_area = parse_target_area(&input);
+    let input = util::read_input_file("examples/day17/example.txt").unwrap();
+    let target_area = parse_target_area(&input).unwrap();
     let (y_max, _) = find_initial_velocity(&target_area);
     assert_eq!(y_max, 45);
 }
 
 #[test]
 fn test_day17_part1_solution() {
-    let input = util::read_input_file("day17.txt");
-    let target_area = parse_target_area(&input);
+    let input = util::read_input_file("day17/input.txt").unwrap();
+    let target_area = parse_target_area(&input).unwrap();
     let (y_max, _) = find_initial_velocity(&target_area);
     assert_eq!(y_max, 5886);
 }
 
 #[test]
 fn test_day17_part2_example() {
-    let input = String::from("target area: x=20..30, y=-10..-5");
-    let target_area = parse_target_area(&input);
+    let input = util::read_input_file("examples/day17/example.txt").unwrap();
+    let target_area = parse_target_area(&input).unwrap();
     let (_, hit_count) = find_initial_velocity(&target_area);
     assert_eq!(hit_count, 112);
 }
 
 #[test]
 fn test_day17_part2_solution() {
-    let input = util::read_input_file("day17.txt");
-    let target_area = parse_target_area(&input);
+    let input = util::read_input_file("day17/input.txt").unwrap();
+    let target_area = parse_target_area(&input).unwrap();
     let (_, hit_count) = find_initial_velocity(&target_area);
     assert_eq!(hit_count, 1806);
+}
+
+#[test]
+fn test_day17_parse_error() {
+    let error = parse_target_area("target area: x=20..30, y=-10..x").unwrap_err();
+    assert_eq!(error.to_string(), "line 1, column 31: invalid number 'x'");
 }
\ No newline at end of file
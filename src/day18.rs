@@ -1,42 +1,89 @@
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main() {
-    let input = util::read_input_file("day18.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let snailfish_numbers = parse_snailfish_numbers(&lines);
+pub struct Day18;
 
-    let number = add(&snailfish_numbers);
-    let mag = calculate_magnitude(&number);
-    println!("Part 1: Solution={}", mag);
+impl Solution for Day18 {
+    type Input = Vec<Vec<(i32, i32)>>;
 
-    let largest_mag = get_largest_magnitude(&snailfish_numbers);
-    println!("Part 2: Solution={}", largest_mag);
+    fn parse(input: &str) -> Result<Vec<Vec<(i32, i32)>>, Error> {
+        return parse_snailfish_numbers(input);
+    }
+
+    fn part1(snailfish_numbers: &Vec<Vec<(i32, i32)>>) -> Result<Answer, Error> {
+        let number = add(snailfish_numbers);
+        return Ok(calculate_magnitude(&number).into());
+    }
+
+    fn part2(snailfish_numbers: &Vec<Vec<(i32, i32)>>) -> Result<Answer, Error> {
+        return Ok(get_largest_magnitude(snailfish_numbers).into());
+    }
 }
 
-fn parse_snailfish_numbers(lines: &Vec<String>) -> Vec<Vec<(i32, i32)>> {
-    return lines.iter().map(|line| parse_snailfish_number(&line)).collect();
+pub fn parse_snailfish_numbers(input: &str) -> Result<Vec<Vec<(i32, i32)>>, Error> {
+    let numbers = util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line)| parse_snailfish_number(line, *line_number)))?;
+    if numbers.len() < 2 {
+        return Err(Error::input(format!("expected at least two snailfish numbers, found {}", numbers.len())));
+    }
+
+    return Ok(numbers);
 }
 
-fn parse_snailfish_number(line: &String) -> Vec<(i32, i32)> {
+// Flattens a snailfish number into (depth, value) pairs, e.g. [[1,2],3] becomes (2,1), (2,2), (1,3)
+pub fn parse_snailfish_number(line: &str, line_number: usize) -> Result<Vec<(i32, i32)>, Error> {
+    let chars: Vec<char> = line.chars().collect();
     let mut number: Vec<(i32, i32)> = vec![];
-    let mut depth = 0;
-
-    for c in line.chars() {
-        match c {
-            '[' => depth += 1,
-            ']' => depth -= 1,
-            ',' => {},
-            _ => number.push((depth, c.to_digit(10).unwrap() as i32)),
+    let mut i = 0;
+
+    if chars.first() != Some(&'[') {
+        return Err(Error::parse(line_number, 1, "a snailfish number must be a pair"));
+    }
+
+    parse_snailfish_element(&chars, &mut i, 0, line_number, &mut number)?;
+    if i < chars.len() {
+        return Err(Error::parse(line_number, i + 1, format!("unexpected '{}' after the snailfish number", chars[i])));
+    }
+
+    return Ok(number);
+}
+
+fn parse_snailfish_element(chars: &Vec<char>, i: &mut usize, depth: i32, line_number: usize, number: &mut Vec<(i32, i32)>) -> Result<(), Error> {
+    let expect = |i: &mut usize, expected: char| -> Result<(), Error> {
+        if chars.get(*i) != Some(&expected) {
+            let found = chars.get(*i).map_or("the end of the line".to_string(), |c| format!("'{}'", c));
+            return Err(Error::parse(line_number, *i + 1, format!("expected '{}', found {}", expected, found)));
+        }
+        *i += 1;
+        return Ok(());
+    };
+
+    if chars.get(*i) == Some(&'[') {
+        // Numbers nested inside more than 4 pairs would already have been exploded
+        if depth == 4 {
+            return Err(Error::parse(line_number, *i + 1, "the snailfish number is nested inside more than 4 pairs"));
         }
+
+        expect(i, '[')?;
+        parse_snailfish_element(chars, i, depth + 1, line_number, number)?;
+        expect(i, ',')?;
+        parse_snailfish_element(chars, i, depth + 1, line_number, number)?;
+        return expect(i, ']');
+    }
+
+    match chars.get(*i).and_then(|c| c.to_digit(10)) {
+        Some(digit) => number.push((depth, digit as i32)),
+        None => return Err(Error::parse(line_number, *i + 1, "expected a digit or '['")),
     }
+    *i += 1;
 
-    return number;
+    return Ok(());
 }
 
-fn add(numbers: &Vec<Vec<(i32, i32)>>) -> Vec<(i32, i32)> {
+pub fn add(numbers: &Vec<Vec<(i32, i32)>>) -> Vec<(i32, i32)> {
     let mut mut_numbers = numbers.clone();
 
-    for i in 0..numbers.len()-1 {
+    for _ in 0..numbers.len()-1 {
         let next = &mut mut_numbers.remove(1);
         let first = &mut mut_numbers[0];
         first.append(next);
@@ -47,7 +94,7 @@ fn add(numbers: &Vec<Vec<(i32, i32)>>) -> Vec<(i32, i32)> {
     return mut_numbers.remove(0);
 }
 
-fn reduce(number: &mut Vec<(i32, i32)>, start: usize) {
+pub fn reduce(number: &mut Vec<(i32, i32)>, start: usize) {
     // explode
     for i in start..number.len()-1 {
         if number[i].0 == 5 { // nested inside 4 pairs
@@ -76,21 +123,21 @@ fn reduce(number: &mut Vec<(i32, i32)>, start: usize) {
     }
 }
 
-fn calculate_magnitude(number: &Vec<(i32, i32)>) -> i32 {
+pub fn calculate_magnitude(number: &Vec<(i32, i32)>) -> i32 {
     return magnitude(number, &mut 0, 1);
 }
 
-fn magnitude(number: &Vec<(i32, i32)>, i: &mut usize, depth: i32) -> i32 {
+pub fn magnitude(number: &Vec<(i32, i32)>, i: &mut usize, depth: i32) -> i32 {
     let left: i32 = if number[*i].0 == depth {
         *i += 1;
-        number[*i - 1].1 as i32
+        number[*i - 1].1
     } else {
         magnitude(number, i, depth + 1)
     };
 
     let right = if number[*i].0 == depth {
         *i += 1;
-        number[*i - 1].1 as i32
+        number[*i - 1].1
     } else {
         magnitude(number, i, depth + 1)
     };
@@ -98,7 +145,7 @@ fn magnitude(number: &Vec<(i32, i32)>, i: &mut usize, depth: i32) -> i32 {
     return 3 * left + 2 * right;
 }
 
-fn get_largest_magnitude(numbers: &Vec<Vec<(i32, i32)>>) -> i32 {
+pub fn get_largest_magnitude(numbers: &Vec<Vec<(i32, i32)>>) -> i32 {
     let mut largest_magnitude = 0;
 
     for i in 0..numbers.len()-2 {
@@ -131,18 +178,8 @@ fn get_largest_magnitude(numbers: &Vec<Vec<(i32, i32)>>) -> i32 {
 
 #[test]
 fn test_day18_part1_example1() {
-    let input = String::from("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]");
-    let lines = util::split_string_by_string(&input, "\n");
-    let snailfish_numbers = parse_snailfish_numbers(&lines);
+    let input = util::read_input_file("examples/day18/example.txt").unwrap();
+    let snailfish_numbers = parse_snailfish_numbers(&input).unwrap();
     let number = add(&snailfish_numbers);
     let mag = calculate_magnitude(&number);
     assert_eq!(mag, 4140);
@@ -150,9 +187,8 @@ fn test_day18_part1_example1() {
 
 #[test]
 fn test_day18_part1_solution() {
-    let input = util::read_input_file("day18.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let snailfish_numbers = parse_snailfish_numbers(&lines);
+    let input = util::read_input_file("day18/input.txt").unwrap();
+    let snailfish_numbers = parse_snailfish_numbers(&input).unwrap();
     let number = add(&snailfish_numbers);
     let mag = calculate_magnitude(&number);
     assert_eq!(mag, 3816);
@@ -160,27 +196,16 @@ fn test_day18_part1_solution() {
 
 #[test]
 fn test_day18_part2_example1() {
-    let input = String::from("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]");
-    let lines = util::split_string_by_string(&input, "\n");
-    let snailfish_numbers = parse_snailfish_numbers(&lines);
+    let input = util::read_input_file("examples/day18/example.txt").unwrap();
+    let snailfish_numbers = parse_snailfish_numbers(&input).unwrap();
     let largest_mag = get_largest_magnitude(&snailfish_numbers);
     assert_eq!(largest_mag, 3993);
 }
 
 #[test]
 fn test_day18_part2_solution() {
-    let input = util::read_input_file("day18.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let snailfish_numbers = parse_snailfish_numbers(&lines);
+    let input = util::read_input_file("day18/input.txt").unwrap();
+    let snailfish_numbers = parse_snailfish_numbers(&input).unwrap();
     let largest_mag = get_largest_magnitude(&snailfish_numbers);
     assert_eq!(largest_mag, 4819);
 }
\ No newline at end of file
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub type Position = [i32; 3];
+
+// Two scanners are aligned if at least this many beacons overlap
+const MIN_OVERLAPPING_BEACONS: usize = 12;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = ScannerMap;
+
+    // Scanners that can't be aligned make the input invalid, so the alignment is already done while parsing
+    fn parse(input: &str) -> Result<ScannerMap, Error> {
+        let scanners = parse_scanners(input)?;
+        return align_scanners(&scanners);
+    }
+
+    // Aligning the scanners already solves the puzzle
+    fn check(input: &str) -> Result<(), Error> {
+        return parse_scanners(input).map(|_| ());
+    }
+
+    fn part1(map: &ScannerMap) -> Result<Answer, Error> {
+        return Ok(map.beacons.len().into());
+    }
+
+    fn part2(map: &ScannerMap) -> Result<Answer, Error> {
+        return Ok(get_largest_distance(&map.scanner_positions).into());
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Scanner {
+    pub id: usize,
+    pub beacons: Vec<Position>,
+}
+
+/// All beacons and scanner positions in the coordinates of scanner 0.
+#[derive(Clone, Debug)]
+pub struct ScannerMap {
+    pub beacons: HashSet<Position>,
+    pub scanner_positions: Vec<Position>,
+}
+
+/// A rotation that maps axis `permutation[i]` of a position to axis `i` and multiplies it with `signs[i]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rotation {
+    pub permutation: [usize; 3],
+    pub signs: [i32; 3],
+}
+
+impl Rotation {
+    pub fn apply(&self, position: &Position) -> Position {
+        return [0, 1, 2].map(|i| position[self.permutation[i]] * self.signs[i]);
+    }
+}
+
+// All 24 orientations a scanner can face: the axis permutations and sign flips that don't mirror the space
+pub fn get_rotations() -> Vec<Rotation> {
+    let permutations = [([0, 1, 2], 1), ([1, 2, 0], 1), ([2, 0, 1], 1), ([0, 2, 1], -1), ([2, 1, 0], -1), ([1, 0, 2], -1)];
+    let mut rotations: Vec<Rotation> = vec![];
+
+    for (permutation, parity) in permutations {
+        for sign_bits in 0..8 {
+            let signs = [0, 1, 2].map(|i| if sign_bits & (1 << i) == 0 { 1 } else { -1 });
+            if parity * signs[0] * signs[1] * signs[2] == 1 {
+                rotations.push(Rotation { permutation, signs });
+            }
+        }
+    }
+
+    return rotations;
+}
+
+pub fn parse_scanners(input: &str) -> Result<Vec<Scanner>, Error> {
+    let scanners = util::collect_results(util::numbered_blocks(input).iter().map(|block| parse_scanner(block)))?;
+
+    if scanners.is_empty() {
+        return Err(Error::input("the input contains no scanners"));
+    }
+
+    return Ok(scanners);
+}
+
+pub fn parse_scanner(block: &[(usize, &str)]) -> Result<Scanner, Error> {
+    let (line_number, header) = block[0];
+    let id = header.strip_prefix("--- scanner ").and_then(|x| x.strip_suffix(" ---")).ok_or_else(|| {
+        Error::parse(line_number, 1, format!("expected '--- scanner <id> ---', found '{}'", header))
+    }).and_then(|id| util::parse_value::<usize>(id, line_number, 13));
+
+    let beacons = util::collect_results(block[1..].iter().map(|(line_number, line)| {
+        let coordinates = util::parse_separated::<i32>(line, ",", *line_number, 1)?;
+        if coordinates.len() != 3 {
+            return Err(Error::parse(*line_number, 1, format!("expected a beacon 'x,y,z', found '{}'", line)));
+        }
+        return Ok([coordinates[0], coordinates[1], coordinates[2]]);
+    }));
+
+    return match (id, beacons) {
+        (Ok(id), Ok(beacons)) => Ok(Scanner { id, beacons }),
+        (id, beacons) => Err(Error::combine(id.err().into_iter().chain(beacons.err()).collect())),
+    };
+}
+
+// Squared distances between all beacons of a scanner don't depend on its position and orientation
+pub fn get_fingerprint(beacons: &Vec<Position>) -> HashMap<i64, usize> {
+    let mut fingerprint: HashMap<i64, usize> = HashMap::new();
+
+    for i in 0..beacons.len() {
+        for j in i+1..beacons.len() {
+            let distance: i64 = (0..3).map(|axis| ((beacons[i][axis] - beacons[j][axis]) as i64).pow(2)).sum();
+            *fingerprint.entry(distance).or_insert(0) += 1;
+        }
+    }
+
+    return fingerprint;
+}
+
+fn may_overlap(fingerprint1: &HashMap<i64, usize>, fingerprint2: &HashMap<i64, usize>) -> bool {
+    let common: usize = fingerprint1.iter().map(|(distance, count)| *count.min(fingerprint2.get(distance).unwrap_or(&0))).sum();
+    return common >= MIN_OVERLAPPING_BEACONS * (MIN_OVERLAPPING_BEACONS - 1) / 2;
+}
+
+// Finds the rotation and the position of the scanner relative to the already aligned beacons
+pub fn align_scanner(aligned_beacons: &Vec<Position>, beacons: &Vec<Position>, rotations: &Vec<Rotation>) -> Option<(Vec<Position>, Position)> {
+    for rotation in rotations {
+        let rotated: Vec<Position> = beacons.iter().map(|beacon| rotation.apply(beacon)).collect();
+        let mut offsets: HashMap<Position, usize> = HashMap::new();
+
+        for aligned_beacon in aligned_beacons {
+            for beacon in &rotated {
+                let offset = [0, 1, 2].map(|axis| aligned_beacon[axis] - beacon[axis]);
+                let count = offsets.entry(offset).or_insert(0);
+                *count += 1;
+
+                if *count >= MIN_OVERLAPPING_BEACONS {
+                    let moved = rotated.iter().map(|beacon| [0, 1, 2].map(|axis| beacon[axis] + offset[axis])).collect();
+                    return Some((moved, offset));
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+/// Aligns all scanners to scanner 0, starting from it and aligning every scanner to one that is already aligned.
+pub fn align_scanners(scanners: &Vec<Scanner>) -> Result<ScannerMap, Error> {
+    let rotations = get_rotations();
+    let fingerprints: Vec<HashMap<i64, usize>> = scanners.iter().map(|scanner| get_fingerprint(&scanner.beacons)).collect();
+
+    let mut aligned: Vec<Option<(Vec<Position>, Position)>> = vec![None; scanners.len()];
+    aligned[0] = Some((scanners[0].beacons.clone(), [0, 0, 0]));
+    let mut queue: Vec<usize> = vec![0];
+
+    while let Some(reference) = queue.pop() {
+        let reference_beacons = aligned[reference].as_ref().unwrap().0.clone();
+
+        for i in 0..scanners.len() {
+            if aligned[i].is_some() || !may_overlap(&fingerprints[reference], &fingerprints[i]) {
+                continue;
+            }
+
+            if let Some(alignment) = align_scanner(&reference_beacons, &scanners[i].beacons, &rotations) {
+                aligned[i] = Some(alignment);
+                queue.push(i);
+            }
+        }
+    }
+
+    if let Some(i) = aligned.iter().position(|x| x.is_none()) {
+        return Err(Error::input(format!("scanner {} does not overlap with any other scanner", scanners[i].id)));
+    }
+
+    let mut beacons: HashSet<Position> = HashSet::new();
+    let mut scanner_positions: Vec<Position> = vec![];
+    for (scanner_beacons, scanner_position) in aligned.into_iter().flatten() {
+        beacons.extend(scanner_beacons);
+        scanner_positions.push(scanner_position);
+    }
+
+    return Ok(ScannerMap { beacons, scanner_positions });
+}
+
+pub fn get_largest_distance(positions: &Vec<Position>) -> i32 {
+    let mut largest_distance = 0;
+
+    for a in positions {
+        for b in positions {
+            let distance = (0..3).map(|axis| (a[axis] - b[axis]).abs()).sum();
+            largest_distance = largest_distance.max(distance);
+        }
+    }
+
+    return largest_distance;
+}
+
+#[test]
+fn test_day19_part1_example() {
+    let input = util::read_input_file("examples/day19/example.txt").unwrap();
+    let scanners = parse_scanners(&input).unwrap();
+    let map = align_scanners(&scanners).unwrap();
+    assert_eq!(map.beacons.len(), 79);
+}
+
+#[test]
+fn test_day19_part1_solution() {
+    let input = util::read_input_file("day19/input.txt").unwrap();
+    let scanners = parse_scanners(&input).unwrap();
+    let map = align_scanners(&scanners).unwrap();
+    assert_eq!(map.beacons.len(), 684);
+}
+
+#[test]
+fn test_day19_part2_example() {
+    let input = util::read_input_file("examples/day19/example.txt").unwrap();
+    let scanners = parse_scanners(&input).unwrap();
+    let map = align_scanners(&scanners).unwrap();
+    assert_eq!(map.scanner_positions, vec![[0, 0, 0], [68, -1246, -43], [1105, -1205, 1229], [-92, -2380, -20], [-20, -1133, 1061]]);
+    assert_eq!(get_largest_distance(&map.scanner_positions), 3621);
+}
+
+#[test]
+fn test_day19_part2_solution() {
+    let input = util::read_input_file("day19/input.txt").unwrap();
+    let scanners = parse_scanners(&input).unwrap();
+    let map = align_scanners(&scanners).unwrap();
+    assert_eq!(get_largest_distance(&map.scanner_positions), 17699);
+}
+
+#[test]
+fn test_day19_rotations() {
+    let rotations = get_rotations();
+    let orientations: HashSet<Position> = rotations.iter().map(|rotation| rotation.apply(&[1, 2, 3])).collect();
+    assert_eq!(orientations.len(), 24);
+}
@@ -1,88 +1,178 @@
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-struct Position
+pub mod trajectory;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position
 {
-    forward: i32,
-    depth: i32,
+    pub forward: i32,
+    pub depth: i32,
 }
 
-fn main()
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Command
 {
-    let input = util::read_input_file("day2.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    
-    let position = get_position(&lines, false);
-
-    println!("Part 1: Solution={}", position.forward * position.depth);
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
 
-    let position_with_aim = get_position(&lines, true);
+impl Command
+{
+    pub fn direction(&self) -> &'static str
+    {
+        return match self
+        {
+            Command::Forward(_) => "forward",
+            Command::Down(_) => "down",
+            Command::Up(_) => "up",
+        };
+    }
 
-    println!("Part 2: Solution={}", position_with_aim.forward * position_with_aim.depth);
+    pub fn units(&self) -> i32
+    {
+        return match *self
+        {
+            Command::Forward(units) | Command::Down(units) | Command::Up(units) => units,
+        };
+    }
 }
 
-fn get_position(lines: &Vec<String>, use_aim: bool) -> Position
+/// How a submarine moves on a command. The interpreter owns the position, a model can keep its own state (e.g. the
+/// aim) and can wrap another model to change its moves.
+pub trait SubmarineModel
 {
-    let mut position = Position {
-        forward: 0, 
-        depth: 0
-    };
+    fn apply(&mut self, position: &mut Position, command: &Command);
+
+    /// The current aim, if the model has one.
+    fn aim(&self) -> Option<i32>
+    {
+        return None;
+    }
+}
 
-    let mut aim: i32 = 0;
+/// Down and up change the depth directly (part 1).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DirectModel;
 
-    for line in lines
+impl SubmarineModel for DirectModel
+{
+    fn apply(&mut self, position: &mut Position, command: &Command)
     {
-        let words: Vec<String> = line.split(" ").map(String::from).collect();
-        let movement: &String = &words[0];
-        let value: i32 = words[1].trim().parse::<i32>().unwrap();
-        if movement == "forward"
+        match *command
         {
-            position.forward += value;
-            if use_aim
-            {
-                position.depth += aim * value;
-            }
-        }
-        else if movement == "down"
-        {
-            if use_aim
-            {
-                aim += value;
-            }
-            else
-            {
-                position.depth += value;
-            }
+            Command::Forward(units) => position.forward += units,
+            Command::Down(units) => position.depth += units,
+            Command::Up(units) => position.depth -= units,
         }
-        else if movement == "up"
+    }
+}
+
+/// Down and up change the aim, forward also dives by the aim times the units (part 2).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AimModel
+{
+    pub aim: i32,
+}
+
+impl SubmarineModel for AimModel
+{
+    fn apply(&mut self, position: &mut Position, command: &Command)
+    {
+        match *command
         {
-            if use_aim
+            Command::Forward(units) =>
             {
-                aim -= value;
-            }
-            else
-            {
-                position.depth -= value;
-            }
+                position.forward += units;
+                position.depth += self.aim * units;
+            },
+            Command::Down(units) => self.aim += units,
+            Command::Up(units) => self.aim -= units,
         }
     }
 
+    fn aim(&self) -> Option<i32>
+    {
+        return Some(self.aim);
+    }
+}
+
+/// Returns a new model by its name on the command line, or None if there is no such model.
+pub fn get_model(name: &str) -> Option<Box<dyn SubmarineModel>>
+{
+    return match name
+    {
+        "direct" => Some(Box::new(DirectModel)),
+        "aim" => Some(Box::new(AimModel::default())),
+        _ => None,
+    };
+}
+
+pub struct Day2;
+
+impl Solution for Day2
+{
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Vec<Command>, Error>
+    {
+        return util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line)| parse_command(line, *line_number)));
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<Answer, Error>
+    {
+        let position = get_position(commands, &mut DirectModel);
+        return Ok((position.forward * position.depth).into());
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<Answer, Error>
+    {
+        let position_with_aim = get_position(commands, &mut AimModel::default());
+        return Ok((position_with_aim.forward * position_with_aim.depth).into());
+    }
+}
+
+pub fn parse_command(line: &str, line_number: usize) -> Result<Command, Error>
+{
+    let words = util::split_with_columns(line, " ");
+    if words.len() != 2
+    {
+        return Err(Error::parse(line_number, 1, format!("expected '<direction> <units>', found '{}'", line)));
+    }
+
+    let (column, value) = words[1];
+    let units = util::parse_value::<i32>(value, line_number, column)?;
+
+    return match words[0].1
+    {
+        "forward" => Ok(Command::Forward(units)),
+        "down" => Ok(Command::Down(units)),
+        "up" => Ok(Command::Up(units)),
+        direction => Err(Error::parse(line_number, 1, format!("unknown direction '{}', expected forward, down or up", direction))),
+    };
+}
+
+pub fn get_position<M: SubmarineModel>(commands: &Vec<Command>, model: &mut M) -> Position
+{
+    let mut position = Position::default();
+
+    for command in commands
+    {
+        model.apply(&mut position, command);
+    }
+
     return position;
 }
 
 #[test]
 fn test_day2_part1_example()
 {
-    let input: String = "
-forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2
-".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
+    let input = util::read_input_file("examples/day2/example.txt").unwrap();
+    let commands = Day2::parse(&input).unwrap();
     
-    let position = get_position(&lines, false);
+    let position = get_position(&commands, &mut DirectModel);
 
     assert_eq!(position.forward, 15);
     assert_eq!(position.depth, 10);
@@ -91,10 +181,10 @@ forward 2
 #[test]
 fn test_day2_part1_solution()
 {
-    let input = util::read_input_file("day2.txt");
-    let lines = util::split_string_by_string(&input, "\n");
+    let input = util::read_input_file("day2/input.txt").unwrap();
+    let commands = Day2::parse(&input).unwrap();
     
-    let position = get_position(&lines, false);
+    let position = get_position(&commands, &mut DirectModel);
 
     assert_eq!(position.forward, 2085);
     assert_eq!(position.depth, 785);
@@ -103,17 +193,10 @@ fn test_day2_part1_solution()
 #[test]
 fn test_day2_part2_example()
 {
-    let input: String = "
-forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2
-".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
+    let input = util::read_input_file("examples/day2/example.txt").unwrap();
+    let commands = Day2::parse(&input).unwrap();
     
-    let position = get_position(&lines, true);
+    let position = get_position(&commands, &mut AimModel::default());
 
     assert_eq!(position.forward, 15);
     assert_eq!(position.depth, 60);
@@ -122,11 +205,49 @@ forward 2
 #[test]
 fn test_day2_part2_solution()
 {
-    let input = util::read_input_file("day2.txt");
-    let lines = util::split_string_by_string(&input, "\n");
+    let input = util::read_input_file("day2/input.txt").unwrap();
+    let commands = Day2::parse(&input).unwrap();
     
-    let position = get_position(&lines, true);
+    let position = get_position(&commands, &mut AimModel::default());
 
     assert_eq!(position.forward, 2085);
     assert_eq!(position.depth, 898205);
+}
+
+#[test]
+fn test_day2_parse_errors()
+{
+    let error = Day2::parse("forward 5\nsideways 3\ndown x\nup\n").unwrap_err();
+    let problems: Vec<String> = error.problems().iter().map(|problem| problem.to_string()).collect();
+
+    assert_eq!(problems, vec![
+        "line 2, column 1: unknown direction 'sideways', expected forward, down or up",
+        "line 3, column 6: invalid number 'x'",
+        "line 4, column 1: expected '<direction> <units>', found 'up'",
+    ]);
+}
+
+#[test]
+fn test_day2_custom_model()
+{
+    // Keeps the submarine between the surface and a maximum depth, on top of another model
+    struct BoundedDepthModel<M: SubmarineModel>
+    {
+        model: M,
+        max_depth: i32,
+    }
+
+    impl<M: SubmarineModel> SubmarineModel for BoundedDepthModel<M>
+    {
+        fn apply(&mut self, position: &mut Position, command: &Command)
+        {
+            self.model.apply(position, command);
+            position.depth = position.depth.clamp(0, self.max_depth);
+        }
+    }
+
+    let commands = vec![Command::Up(3), Command::Down(5), Command::Forward(4), Command::Down(10), Command::Forward(1)];
+
+    assert_eq!(get_position(&commands, &mut BoundedDepthModel { model: DirectModel, max_depth: 8 }), Position { forward: 5, depth: 8 });
+    assert_eq!(get_position(&commands, &mut BoundedDepthModel { model: AimModel::default(), max_depth: 15 }), Position { forward: 5, depth: 15 });
 }
\ No newline at end of file
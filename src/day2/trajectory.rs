@@ -0,0 +1,126 @@
+use crate::day2::{Command, Position, SubmarineModel};
+#[cfg(test)]
+use crate::day2::{AimModel, Day2, DirectModel};
+#[cfg(test)]
+use crate::solution::Solution;
+#[cfg(test)]
+use crate::util;
+
+/// The state of the submarine after a command.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Step {
+    pub command: Command,
+    pub position: Position,
+    /// None if the model has no aim.
+    pub aim: Option<i32>,
+}
+
+/// The path of the submarine, one step per command. Steps are numbered from 1 in the exports and the metrics.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trajectory {
+    pub steps: Vec<Step>,
+}
+
+/// Runs the commands like `get_position` and records every step.
+pub fn record_trajectory<M: SubmarineModel + ?Sized>(commands: &Vec<Command>, model: &mut M) -> Trajectory {
+    let mut position = Position::default();
+    let mut steps: Vec<Step> = vec![];
+
+    for command in commands {
+        model.apply(&mut position, command);
+        steps.push(Step { command: *command, position, aim: model.aim() });
+    }
+
+    return Trajectory { steps };
+}
+
+impl Trajectory {
+    pub fn final_position(&self) -> Position {
+        return self.steps.last().map(|step| step.position).unwrap_or_default();
+    }
+
+    /// The deepest depth and the first step that reached it, None without steps.
+    pub fn max_depth(&self) -> Option<(usize, i32)> {
+        let depth = self.steps.iter().map(|step| step.position.depth).max()?;
+        let index = self.steps.iter().position(|step| step.position.depth == depth).unwrap();
+
+        return Some((index + 1, depth));
+    }
+
+    /// The first step that is deeper than the given depth.
+    pub fn first_step_below(&self, depth: i32) -> Option<usize> {
+        return self.steps.iter().position(|step| step.position.depth > depth).map(|index| index + 1);
+    }
+
+    /// One row per step, the aim is empty if the model has none.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,command,units,forward,depth,aim\n");
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let aim = step.aim.map(|aim| aim.to_string()).unwrap_or_default();
+            csv += &format!("{},{},{},{},{},{}\n", index + 1, step.command.direction(), step.command.units(), step.position.forward, step.position.depth, aim);
+        }
+
+        return csv;
+    }
+
+    /// A JSON object with the metrics and the steps, `first_below` is only included for a given depth, e.g.
+    /// `{"max_depth": {"step": 2, "depth": 8}, "first_below": {"depth": 5, "step": 2}, "steps": [...]}`.
+    pub fn to_json(&self, below: Option<i32>) -> String {
+        let mut fields: Vec<String> = vec![];
+
+        fields.push(match self.max_depth() {
+            Some((step, depth)) => format!("  \"max_depth\": {{\"step\": {}, \"depth\": {}}}", step, depth),
+            None => String::from("  \"max_depth\": null"),
+        });
+
+        if let Some(depth) = below {
+            let step = self.first_step_below(depth).map(|step| step.to_string()).unwrap_or(String::from("null"));
+            fields.push(format!("  \"first_below\": {{\"depth\": {}, \"step\": {}}}", depth, step));
+        }
+
+        let steps: Vec<String> = self.steps.iter().enumerate().map(|(index, step)| {
+            let aim = step.aim.map(|aim| aim.to_string()).unwrap_or(String::from("null"));
+            return format!("    {{\"step\": {}, \"command\": \"{}\", \"units\": {}, \"forward\": {}, \"depth\": {}, \"aim\": {}}}",
+                index + 1, step.command.direction(), step.command.units(), step.position.forward, step.position.depth, aim);
+        }).collect();
+
+        fields.push(if steps.is_empty() { String::from("  \"steps\": []") } else { format!("  \"steps\": [\n{}\n  ]", steps.join(",\n")) });
+
+        return format!("{{\n{}\n}}", fields.join(",\n"));
+    }
+}
+
+#[test]
+fn test_day2_trajectory_example() {
+    let input = util::read_input_file("examples/day2/example.txt").unwrap();
+    let commands = Day2::parse(&input).unwrap();
+
+    let direct = record_trajectory(&commands, &mut DirectModel);
+    let aim = record_trajectory(&commands, &mut AimModel::default());
+
+    assert_eq!(direct.final_position(), Position { forward: 15, depth: 10 });
+    assert_eq!(direct.max_depth(), Some((5, 10)));
+    assert_eq!(aim.final_position(), Position { forward: 15, depth: 60 });
+    assert_eq!(aim.max_depth(), Some((6, 60)));
+    assert_eq!(aim.first_step_below(39), Some(3));
+    assert_eq!(aim.first_step_below(60), None);
+    assert_eq!(aim.steps[2].aim, Some(5));
+    assert_eq!(direct.steps[2].aim, None);
+
+    assert_eq!(record_trajectory(&commands, super::get_model("aim").unwrap().as_mut()), aim);
+    assert_eq!(record_trajectory(&commands, super::get_model("direct").unwrap().as_mut()), direct);
+    assert!(super::get_model("drift").is_none());
+}
+
+#[test]
+fn test_day2_trajectory_export() {
+    let commands = vec![Command::Down(2), Command::Forward(3)];
+    let trajectory = record_trajectory(&commands, &mut AimModel::default());
+
+    assert_eq!(trajectory.to_csv(), "step,command,units,forward,depth,aim\n1,down,2,0,0,2\n2,forward,3,3,6,2\n");
+    assert_eq!(trajectory.to_json(Some(5)), "{\n  \"max_depth\": {\"step\": 2, \"depth\": 6},\n  \"first_below\": {\"depth\": 5, \"step\": 2},\n  \"steps\": [\n    \
+        {\"step\": 1, \"command\": \"down\", \"units\": 2, \"forward\": 0, \"depth\": 0, \"aim\": 2},\n    \
+        {\"step\": 2, \"command\": \"forward\", \"units\": 3, \"forward\": 3, \"depth\": 6, \"aim\": 2}\n  ]\n}");
+    assert_eq!(record_trajectory(&vec![], &mut DirectModel).to_json(None), "{\n  \"max_depth\": null,\n  \"steps\": []\n}");
+}
@@ -0,0 +1,165 @@
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+use crate::util::Grid;
+
+const ALGORITHM_SIZE: usize = 512;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = (Vec<bool>, Image);
+
+    fn parse(input: &str) -> Result<(Vec<bool>, Image), Error> {
+        return parse_algorithm_and_image(input);
+    }
+
+    fn part1((algorithm, image): &(Vec<bool>, Image)) -> Result<Answer, Error> {
+        return count_lit_pixels_after(image, algorithm, 2);
+    }
+
+    fn part2((algorithm, image): &(Vec<bool>, Image)) -> Result<Answer, Error> {
+        return count_lit_pixels_after(image, algorithm, 50);
+    }
+}
+
+/// An infinite image: the pixels inside the grid are stored, all pixels outside have the background value.
+#[derive(Clone, Debug)]
+pub struct Image {
+    pub pixels: Grid<bool>,
+    pub background: bool,
+}
+
+impl Image {
+    pub fn get(&self, x: i64, y: i64) -> bool {
+        if x < 0 || y < 0 {
+            return self.background;
+        }
+
+        return *self.pixels.get(x as usize, y as usize).unwrap_or(&self.background);
+    }
+
+    /// None if the background is lit, then an infinite number of pixels is lit.
+    pub fn count_lit_pixels(&self) -> Option<usize> {
+        if self.background {
+            return None;
+        }
+
+        return Some(self.pixels.iter().filter(|pixel| **pixel).count());
+    }
+}
+
+pub fn parse_algorithm_and_image(input: &str) -> Result<(Vec<bool>, Image), Error> {
+    let blocks = util::numbered_blocks(input);
+    if blocks.len() != 2 || blocks[0].len() != 1 {
+        return Err(Error::input("expected the enhancement algorithm in one line, followed by a blank line and the image"));
+    }
+
+    let algorithm = Grid::parse_chars(&blocks[0], parse_pixel)?.cells().to_vec();
+    if algorithm.len() != ALGORITHM_SIZE {
+        return Err(Error::parse(blocks[0][0].0, 1, format!("expected an algorithm with {} pixels, found {}", ALGORITHM_SIZE, algorithm.len())));
+    }
+
+    if algorithm[0] && algorithm[ALGORITHM_SIZE - 1] {
+        return Err(Error::parse(blocks[0][0].0, 1, "the algorithm lights up the infinite background forever"));
+    }
+
+    let pixels = Grid::parse_chars(&blocks[1], parse_pixel)?;
+    return Ok((algorithm, Image { pixels, background: false }));
+}
+
+fn parse_pixel(c: char) -> Option<bool> {
+    return match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    };
+}
+
+// Every enhancement grows the image by one pixel in every direction
+pub fn enhance(image: &Image, algorithm: &Vec<bool>) -> Image {
+    let pixels = Grid::from_fn(image.pixels.width() + 2, image.pixels.height() + 2, |x, y| {
+        let mut index = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let pixel = image.get(x as i64 + dx - 1, y as i64 + dy - 1);
+                index = (index << 1) | pixel as usize;
+            }
+        }
+        return algorithm[index];
+    });
+
+    // The infinite background flips if a dark 3x3 square turns lit (and the other way around)
+    let background = if image.background { algorithm[ALGORITHM_SIZE - 1] } else { algorithm[0] };
+
+    return Image { pixels, background };
+}
+
+// Parsing rejects algorithms that light the background forever, so it is dark again after an even number of steps
+fn count_lit_pixels_after(image: &Image, algorithm: &Vec<bool>, steps: usize) -> Result<Answer, Error> {
+    let lit_pixels = enhance_times(image, algorithm, steps).count_lit_pixels();
+    return lit_pixels.map(|count| count.into()).ok_or_else(|| Error::input(format!("an infinite number of pixels is lit after {} steps", steps)));
+}
+
+pub fn enhance_times(image: &Image, algorithm: &Vec<bool>, steps: usize) -> Image {
+    let mut enhanced_image = image.clone();
+
+    for _ in 0..steps {
+        enhanced_image = enhance(&enhanced_image, algorithm);
+    }
+
+    return enhanced_image;
+}
+
+#[test]
+fn test_day20_part1_example() {
+    let input = util::read_input_file("examples/day20/example.txt").unwrap();
+    let (algorithm, image) = parse_algorithm_and_image(&input).unwrap();
+    let enhanced_image = enhance_times(&image, &algorithm, 2);
+    assert_eq!(enhanced_image.count_lit_pixels(), Some(35));
+}
+
+#[test]
+fn test_day20_part1_solution() {
+    let input = util::read_input_file("day20/input.txt").unwrap();
+    let (algorithm, image) = parse_algorithm_and_image(&input).unwrap();
+    let enhanced_image = enhance_times(&image, &algorithm, 2);
+    assert_eq!(enhanced_image.count_lit_pixels(), Some(5236));
+}
+
+#[test]
+fn test_day20_part2_example() {
+    let input = util::read_input_file("examples/day20/example.txt").unwrap();
+    let (algorithm, image) = parse_algorithm_and_image(&input).unwrap();
+    let enhanced_image = enhance_times(&image, &algorithm, 50);
+    assert_eq!(enhanced_image.count_lit_pixels(), Some(3351));
+}
+
+#[test]
+fn test_day20_part2_solution() {
+    let input = util::read_input_file("day20/input.txt").unwrap();
+    let (algorithm, image) = parse_algorithm_and_image(&input).unwrap();
+    let enhanced_image = enhance_times(&image, &algorithm, 50);
+    assert_eq!(enhanced_image.count_lit_pixels(), Some(14941));
+}
+
+#[test]
+fn test_day20_background_flips() {
+    // Only a dark 3x3 square turns lit, so the background is lit after the first step and dark after the second
+    let input = format!("#{}\n\n#\n", ".".repeat(ALGORITHM_SIZE - 1));
+    let (algorithm, image) = parse_algorithm_and_image(&input).unwrap();
+
+    // Every pixel of the 3x3 image sees the lit pixel
+    let once = enhance(&image, &algorithm);
+    assert!(once.background);
+    assert_eq!(once.pixels.iter().filter(|pixel| **pixel).count(), 0);
+    assert_eq!(once.count_lit_pixels(), None);
+
+    // Only the center of the 5x5 image sees nothing but the dark 3x3 square
+    let twice = enhance(&once, &algorithm);
+    assert!(!twice.background);
+    assert_eq!(twice.count_lit_pixels(), Some(1));
+    assert!(twice.get(2, 2));
+
+    assert_eq!(Day20::part1(&(algorithm, image)).unwrap(), 1usize.into());
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+const BOARD_SIZE: u32 = 10;
+
+// How often each sum of three rolls of the 3-sided Dirac die occurs (3 = 1+1+1 once, 4 three times, ...)
+const DIRAC_ROLL_FREQUENCIES: [(u32, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (u32, u32);
+
+    fn parse(input: &str) -> Result<(u32, u32), Error> {
+        return parse_starting_positions(input);
+    }
+
+    fn part1(positions: &(u32, u32)) -> Result<Answer, Error> {
+        return Ok(play_deterministic_game(*positions).into());
+    }
+
+    fn part2(positions: &(u32, u32)) -> Result<Answer, Error> {
+        let (wins1, wins2) = play_dirac_game(*positions);
+        return Ok(wins1.max(wins2).into());
+    }
+}
+
+pub fn parse_starting_positions(input: &str) -> Result<(u32, u32), Error> {
+    let lines = util::numbered_lines(input);
+    if lines.len() != 2 {
+        return Err(Error::input(format!("expected the starting positions of two players, found {} lines", lines.len())));
+    }
+
+    let mut positions: Vec<u32> = vec![];
+    for (player, (line_number, line)) in lines.iter().enumerate() {
+        let prefix = format!("Player {} starting position: ", player + 1);
+        let position = line.strip_prefix(&prefix).ok_or_else(|| Error::parse(*line_number, 1, format!("expected '{}<position>'", prefix)))?;
+        let position = util::parse_value::<u32>(position, *line_number, prefix.len() + 1)?;
+        if !(1..=BOARD_SIZE).contains(&position) {
+            return Err(Error::parse(*line_number, prefix.len() + 1, format!("position {} is outside of 1..{}", position, BOARD_SIZE)));
+        }
+        positions.push(position);
+    }
+
+    return Ok((positions[0], positions[1]));
+}
+
+fn move_pawn(position: u32, steps: u32) -> u32 {
+    return (position + steps - 1) % BOARD_SIZE + 1;
+}
+
+// Returns the score of the losing player multiplied by the number of die rolls
+pub fn play_deterministic_game(positions: (u32, u32)) -> u64 {
+    let mut positions = [positions.0, positions.1];
+    let mut scores = [0, 0];
+    let mut rolls: u32 = 0;
+
+    for player in [0, 1].iter().cycle() {
+        // The die rolls 1..100 in order, three times per turn
+        let steps: u32 = (0..3).map(|i| (rolls + i) % 100 + 1).sum();
+        rolls += 3;
+
+        positions[*player] = move_pawn(positions[*player], steps);
+        scores[*player] += positions[*player];
+
+        if scores[*player] >= 1000 {
+            return scores[1 - player] as u64 * rolls as u64;
+        }
+    }
+
+    unreachable!();
+}
+
+/// Returns in how many universes each player wins with the Dirac die.
+pub fn play_dirac_game(positions: (u32, u32)) -> (u64, u64) {
+    return count_wins(positions.0, 0, positions.1, 0, &mut HashMap::new());
+}
+
+// Instead of playing every universe, all universes with the same state are counted at once (like the fish timers of day 6).
+// The state is always seen from the player whose turn it is, so the result is (wins of current player, wins of other player).
+fn count_wins(position: u32, score: u32, other_position: u32, other_score: u32, cache: &mut HashMap<(u32, u32, u32, u32), (u64, u64)>) -> (u64, u64) {
+    if other_score >= 21 {
+        return (0, 1);
+    }
+
+    let state = (position, score, other_position, other_score);
+    if let Some(wins) = cache.get(&state) {
+        return *wins;
+    }
+
+    let mut wins = (0, 0);
+    for (steps, frequency) in DIRAC_ROLL_FREQUENCIES {
+        let new_position = move_pawn(position, steps);
+        let (other_wins, own_wins) = count_wins(other_position, other_score, new_position, score + new_position, cache);
+        wins.0 += own_wins * frequency;
+        wins.1 += other_wins * frequency;
+    }
+
+    cache.insert(state, wins);
+    return wins;
+}
+
+#[test]
+fn test_day21_part1_example() {
+    let input = util::read_input_file("examples/day21/example.txt").unwrap();
+    let positions = parse_starting_positions(&input).unwrap();
+    assert_eq!(play_deterministic_game(positions), 739785);
+}
+
+#[test]
+fn test_day21_part1_solution() {
+    let input = util::read_input_file("day21/input.txt").unwrap();
+    let positions = parse_starting_positions(&input).unwrap();
+    assert_eq!(play_deterministic_game(positions), 551901);
+}
+
+#[test]
+fn test_day21_part2_example() {
+    let input = util::read_input_file("examples/day21/example.txt").unwrap();
+    let positions = parse_starting_positions(&input).unwrap();
+    assert_eq!(play_dirac_game(positions), (444356092776315, 341960390180808));
+}
+
+#[test]
+fn test_day21_part2_solution() {
+    let input = util::read_input_file("day21/input.txt").unwrap();
+    let positions = parse_starting_positions(&input).unwrap();
+    assert_eq!(play_dirac_game(positions), (272847859601291, 193861680728600));
+}
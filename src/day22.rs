@@ -0,0 +1,162 @@
+use crate::day17;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+// The initialization procedure only uses the cubes in -50..50 on every axis
+const INITIALIZATION_REGION: Cuboid = Cuboid { x_min: -50, x_max: 50, y_min: -50, y_max: 50, z_min: -50, z_max: 50 };
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<RebootStep>;
+
+    fn parse(input: &str) -> Result<Vec<RebootStep>, Error> {
+        return parse_reboot_steps(input);
+    }
+
+    fn part1(steps: &Vec<RebootStep>) -> Result<Answer, Error> {
+        return Ok(count_lit_cubes(steps, Some(&INITIALIZATION_REGION)).into());
+    }
+
+    fn part2(steps: &Vec<RebootStep>) -> Result<Answer, Error> {
+        return Ok(count_lit_cubes(steps, None).into());
+    }
+}
+
+/// A cuboid of cubes, all bounds are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cuboid {
+    pub x_min: i32,
+    pub x_max: i32,
+    pub y_min: i32,
+    pub y_max: i32,
+    pub z_min: i32,
+    pub z_max: i32,
+}
+
+impl Cuboid {
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let intersection = Cuboid {
+            x_min: self.x_min.max(other.x_min),
+            x_max: self.x_max.min(other.x_max),
+            y_min: self.y_min.max(other.y_min),
+            y_max: self.y_max.min(other.y_max),
+            z_min: self.z_min.max(other.z_min),
+            z_max: self.z_max.min(other.z_max),
+        };
+
+        let is_empty = intersection.x_min > intersection.x_max || intersection.y_min > intersection.y_max || intersection.z_min > intersection.z_max;
+        return if is_empty { None } else { Some(intersection) };
+    }
+
+    pub fn volume(&self) -> i64 {
+        return (self.x_max as i64 - self.x_min as i64 + 1) * (self.y_max as i64 - self.y_min as i64 + 1) * (self.z_max as i64 - self.z_min as i64 + 1);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RebootStep {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+pub fn parse_reboot_steps(input: &str) -> Result<Vec<RebootStep>, Error> {
+    return util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line)| parse_reboot_step(line, *line_number)));
+}
+
+pub fn parse_reboot_step(line: &str, line_number: usize) -> Result<RebootStep, Error> {
+    let (state, ranges) = line.split_once(' ').unwrap_or((line, ""));
+    let on = match state {
+        "on" => true,
+        "off" => false,
+        _ => return Err(Error::parse(line_number, 1, format!("expected 'on' or 'off', found '{}'", state))),
+    };
+
+    let offset = state.len() + 1;
+    let ranges = util::split_with_columns(ranges, ",");
+    if ranges.len() != 3 {
+        return Err(Error::parse(line_number, offset + 1, format!("expected 'x=<min>..<max>,y=<min>..<max>,z=<min>..<max>', found '{}'", line)));
+    }
+
+    let bounds = util::collect_results(ranges.iter().zip(["x=", "y=", "z="]).map(|((column, range), name)| day17::parse_range(range, name, line_number, offset + column)))?;
+    let cuboid = Cuboid { x_min: bounds[0].0, x_max: bounds[0].1, y_min: bounds[1].0, y_max: bounds[1].1, z_min: bounds[2].0, z_max: bounds[2].1 };
+
+    return Ok(RebootStep { on, cuboid });
+}
+
+// Instead of a voxel array, every step adds its cuboid with a sign: adding a cuboid also adds its intersections
+// with all cuboids so far with the opposite sign, so cubes that are already lit (or off) are not counted twice.
+pub fn count_lit_cubes(steps: &Vec<RebootStep>, region: Option<&Cuboid>) -> i64 {
+    let mut signed_cuboids: Vec<(Cuboid, i64)> = vec![];
+
+    for step in steps {
+        let cuboid = match region {
+            Some(region) => match step.cuboid.intersection(region) {
+                Some(cuboid) => cuboid,
+                None => continue,
+            },
+            None => step.cuboid,
+        };
+
+        let mut new_cuboids: Vec<(Cuboid, i64)> = vec![];
+        for (signed_cuboid, sign) in &signed_cuboids {
+            if let Some(intersection) = cuboid.intersection(signed_cuboid) {
+                new_cuboids.push((intersection, -sign));
+            }
+        }
+
+        if step.on {
+            new_cuboids.push((cuboid, 1));
+        }
+
+        signed_cuboids.append(&mut new_cuboids);
+    }
+
+    return signed_cuboids.iter().map(|(cuboid, sign)| cuboid.volume() * sign).sum();
+}
+
+#[test]
+fn test_day22_part1_example() {
+    let input = util::read_input_file("examples/day22/example1.txt").unwrap();
+    let steps = parse_reboot_steps(&input).unwrap();
+    assert_eq!(count_lit_cubes(&steps, Some(&INITIALIZATION_REGION)), 39);
+
+    let input = util::read_input_file("examples/day22/example2.txt").unwrap();
+    let steps = parse_reboot_steps(&input).unwrap();
+    assert_eq!(count_lit_cubes(&steps, Some(&INITIALIZATION_REGION)), 590784);
+}
+
+#[test]
+fn test_day22_part1_solution() {
+    let input = util::read_input_file("day22/input.txt").unwrap();
+    let steps = parse_reboot_steps(&input).unwrap();
+    assert_eq!(count_lit_cubes(&steps, Some(&INITIALIZATION_REGION)), 20674);
+}
+
+#[test]
+fn test_day22_part2_example() {
+    let input = util::read_input_file("examples/day22/example3.txt").unwrap();
+    let steps = parse_reboot_steps(&input).unwrap();
+    assert_eq!(count_lit_cubes(&steps, Some(&INITIALIZATION_REGION)), 474140);
+    assert_eq!(count_lit_cubes(&steps, None), 2758514936282235);
+
+    let input = util::read_input_file("examples/day22/example4.txt").unwrap();
+    let steps = parse_reboot_steps(&input).unwrap();
+    assert_eq!(count_lit_cubes(&steps, Some(&INITIALIZATION_REGION)), 39);
+    assert_eq!(count_lit_cubes(&steps, None), 39 + 11 * 10 * 2);
+}
+
+#[test]
+fn test_day22_extreme_ranges() {
+    let steps = parse_reboot_steps("on x=-2147483648..2147483647,y=-1..1,z=0..0").unwrap();
+    assert_eq!(steps[0].cuboid.volume(), (1 << 32) * 3);
+    assert_eq!(count_lit_cubes(&steps, None), (1 << 32) * 3);
+}
+
+#[test]
+fn test_day22_part2_solution() {
+    let input = util::read_input_file("day22/input.txt").unwrap();
+    let steps = parse_reboot_steps(&input).unwrap();
+    assert_eq!(count_lit_cubes(&steps, None), 1163687473646390);
+}
@@ -0,0 +1,387 @@
+use std::fmt;
+
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+use crate::util::search;
+
+const HALLWAY_LENGTH: usize = 11;
+const ROOM_COUNT: usize = 4;
+const MAX_DEPTH: usize = 4;
+
+// The two lines that are folded away in the puzzle input, they are inserted between the first and the second row
+const UNFOLDED_ROWS: [[Amphipod; ROOM_COUNT]; 2] = [
+    [Amphipod::Desert, Amphipod::Copper, Amphipod::Bronze, Amphipod::Amber],
+    [Amphipod::Desert, Amphipod::Bronze, Amphipod::Amber, Amphipod::Copper],
+];
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+
+    fn parse(input: &str) -> Result<Burrow, Error> {
+        let burrow = parse_burrow(input)?;
+        if burrow.depth != 2 {
+            return Err(Error::input(format!("expected the folded diagram with rooms of depth 2, found depth {}", burrow.depth)));
+        }
+
+        // The puzzle starts with all amphipods in their rooms, they only stop in the hallway while organizing
+        let hallway: Vec<String> = (0..HALLWAY_LENGTH).filter_map(|x| burrow.hallway[x].map(|amphipod| format!("{} at column {}", amphipod.to_char(), x + 2))).collect();
+        if !hallway.is_empty() {
+            return Err(Error::input(format!("expected an empty hallway, found {}", hallway.join(", "))));
+        }
+
+        return Ok(burrow);
+    }
+
+    fn part1(burrow: &Burrow) -> Result<Answer, Error> {
+        let (energy, _) = organize(burrow).ok_or_else(|| Error::input("the amphipods can't be organized"))?;
+        return Ok(energy.into());
+    }
+
+    // Some burrows that can be organized are stuck after unfolding
+    fn part2(burrow: &Burrow) -> Result<Answer, Error> {
+        let (energy, _) = organize(&unfold(burrow)).ok_or_else(|| Error::input("the amphipods can't be organized after unfolding the diagram"))?;
+        return Ok(energy.into());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    pub fn from_char(c: char) -> Option<Amphipod> {
+        return match c {
+            'A' => Some(Amphipod::Amber),
+            'B' => Some(Amphipod::Bronze),
+            'C' => Some(Amphipod::Copper),
+            'D' => Some(Amphipod::Desert),
+            _ => None,
+        };
+    }
+
+    pub fn to_char(&self) -> char {
+        return match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        };
+    }
+
+    /// Energy needed to move one step.
+    pub fn energy(&self) -> u64 {
+        return match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        };
+    }
+
+    /// Index of the room this amphipod belongs to.
+    pub fn room(&self) -> usize {
+        return *self as usize;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Location {
+    Hallway(usize),
+    /// A room and the spot in it, 0 is the spot next to the hallway.
+    Room(usize, usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub amphipod: Amphipod,
+    pub from: Location,
+    pub to: Location,
+    pub energy: u64,
+}
+
+/// The hallway and the rooms below it, only the first `depth` spots of every room are used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    pub hallway: [Option<Amphipod>; HALLWAY_LENGTH],
+    pub rooms: [[Option<Amphipod>; MAX_DEPTH]; ROOM_COUNT],
+    pub depth: usize,
+}
+
+// The hallway spot right above a room
+fn room_entrance(room: usize) -> usize {
+    return 2 + 2 * room;
+}
+
+fn is_room_entrance(x: usize) -> bool {
+    return (0..ROOM_COUNT).any(|room| room_entrance(room) == x);
+}
+
+impl Burrow {
+    pub fn get(&self, location: Location) -> Option<Amphipod> {
+        return match location {
+            Location::Hallway(x) => self.hallway[x],
+            Location::Room(room, spot) => self.rooms[room][spot],
+        };
+    }
+
+    fn set(&mut self, location: Location, amphipod: Option<Amphipod>) {
+        match location {
+            Location::Hallway(x) => self.hallway[x] = amphipod,
+            Location::Room(room, spot) => self.rooms[room][spot] = amphipod,
+        }
+    }
+
+    pub fn is_organized(&self) -> bool {
+        return (0..ROOM_COUNT).all(|room| self.rooms[room][..self.depth].iter().all(|spot| spot.map(|x| x.room()) == Some(room)));
+    }
+
+    // Amphipods only enter their own room, and only if no other type is still inside
+    fn accepts(&self, room: usize) -> bool {
+        return self.rooms[room][..self.depth].iter().flatten().all(|amphipod| amphipod.room() == room);
+    }
+
+    // All hallway spots between `from` (exclusive) and `to` (inclusive) are empty
+    fn is_hallway_free(&self, from: usize, to: usize) -> bool {
+        let range = if from < to { from + 1..to + 1 } else { to..from };
+        return self.hallway[range].iter().all(|spot| spot.is_none());
+    }
+
+    /// All moves that are allowed from this state: amphipods leave a room for the hallway, or go from the hallway into their room.
+    pub fn moves(&self) -> Vec<Move> {
+        let mut moves: Vec<Move> = vec![];
+
+        for room in 0..ROOM_COUNT {
+            if self.accepts(room) {
+                continue;
+            }
+
+            let spot = match self.rooms[room][..self.depth].iter().position(|spot| spot.is_some()) {
+                Some(spot) => spot,
+                None => continue,
+            };
+            let amphipod = self.rooms[room][spot].unwrap();
+            let entrance = room_entrance(room);
+
+            for x in (0..HALLWAY_LENGTH).filter(|x| !is_room_entrance(*x)) {
+                if self.is_hallway_free(entrance, x) {
+                    let steps = spot + 1 + entrance.abs_diff(x);
+                    moves.push(Move { amphipod, from: Location::Room(room, spot), to: Location::Hallway(x), energy: steps as u64 * amphipod.energy() });
+                }
+            }
+        }
+
+        for x in 0..HALLWAY_LENGTH {
+            let amphipod = match self.hallway[x] {
+                Some(amphipod) => amphipod,
+                None => continue,
+            };
+            let room = amphipod.room();
+            let entrance = room_entrance(room);
+
+            if self.accepts(room) && self.is_hallway_free(x, entrance) {
+                // The amphipod moves down as far as possible
+                let spot = self.rooms[room][..self.depth].iter().rposition(|spot| spot.is_none()).unwrap();
+                let steps = x.abs_diff(entrance) + spot + 1;
+                moves.push(Move { amphipod, from: Location::Hallway(x), to: Location::Room(room, spot), energy: steps as u64 * amphipod.energy() });
+            }
+        }
+
+        return moves;
+    }
+
+    pub fn apply(&self, amphipod_move: &Move) -> Burrow {
+        let mut burrow = *self;
+        burrow.set(amphipod_move.from, None);
+        burrow.set(amphipod_move.to, Some(amphipod_move.amphipod));
+        return burrow;
+    }
+
+    // Every amphipod that is not yet in its final spot has to walk at least to the entrance of its room and one step into it.
+    // This never drops by more than the energy of a move, which A* needs to find the cheapest way.
+    fn minimal_remaining_energy(&self) -> u64 {
+        let mut energy = 0;
+
+        for (x, amphipod) in self.hallway.iter().enumerate() {
+            if let Some(amphipod) = amphipod {
+                energy += (x.abs_diff(room_entrance(amphipod.room())) + 1) as u64 * amphipod.energy();
+            }
+        }
+
+        for room in 0..ROOM_COUNT {
+            for spot in 0..self.depth {
+                let amphipod = match self.rooms[room][spot] {
+                    Some(amphipod) => amphipod,
+                    None => continue,
+                };
+
+                let is_final = self.rooms[room][spot..self.depth].iter().all(|x| x.map(|x| x.room()) == Some(room));
+                if !is_final {
+                    // An amphipod that blocks others in its own room has to step aside into the hallway and back
+                    let distance = room_entrance(room).abs_diff(room_entrance(amphipod.room())).max(2);
+                    energy += (spot + 1 + distance + 1) as u64 * amphipod.energy();
+                }
+            }
+        }
+
+        return energy;
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let to_char = |spot: &Option<Amphipod>| spot.map_or('.', |amphipod| amphipod.to_char());
+
+        writeln!(f, "{}", "#".repeat(HALLWAY_LENGTH + 2))?;
+        writeln!(f, "#{}#", self.hallway.iter().map(to_char).collect::<String>())?;
+
+        for spot in 0..self.depth {
+            let row: Vec<String> = self.rooms.iter().map(|room| to_char(&room[spot]).to_string()).collect();
+            let border = if spot == 0 { "##" } else { "  " };
+            writeln!(f, "{}#{}#{}", border, row.join("#"), if spot == 0 { "##" } else { "" })?;
+        }
+
+        return write!(f, "  {}", "#".repeat(2 * ROOM_COUNT + 1));
+    }
+}
+
+pub fn parse_burrow(input: &str) -> Result<Burrow, Error> {
+    let lines = util::numbered_lines(input);
+    if lines.len() < 4 || lines.len() > MAX_DEPTH + 3 {
+        return Err(Error::input(format!("expected a diagram with rooms of depth 1 to {}, found {} lines", MAX_DEPTH, lines.len())));
+    }
+
+    let mut burrow = Burrow { hallway: [None; HALLWAY_LENGTH], rooms: [[None; MAX_DEPTH]; ROOM_COUNT], depth: lines.len() - 3 };
+
+    let (line_number, hallway) = lines[1];
+    if hallway.len() != HALLWAY_LENGTH + 2 || !hallway.starts_with('#') || !hallway.ends_with('#') {
+        return Err(Error::parse(line_number, 1, format!("expected a hallway '#{}#', found '{}'", ".".repeat(HALLWAY_LENGTH), hallway)));
+    }
+
+    for (x, c) in hallway.chars().skip(1).take(HALLWAY_LENGTH).enumerate() {
+        burrow.hallway[x] = parse_spot(c, line_number, x + 2)?;
+    }
+
+    for (spot, (line_number, line)) in lines[2..lines.len() - 1].iter().enumerate() {
+        let characters: Vec<char> = line.chars().collect();
+
+        for room in 0..ROOM_COUNT {
+            let column = room_entrance(room) + 1;
+            let c = characters.get(column).copied().unwrap_or(' ');
+            burrow.rooms[room][spot] = parse_spot(c, *line_number, column + 1)?;
+        }
+    }
+
+    // Every room must be filled by exactly one type in the end
+    for amphipod in [Amphipod::Amber, Amphipod::Bronze, Amphipod::Copper, Amphipod::Desert] {
+        let count = burrow.hallway.iter().chain(burrow.rooms.iter().flatten()).filter(|x| **x == Some(amphipod)).count();
+        if count != burrow.depth {
+            return Err(Error::input(format!("expected {} amphipods of type {}, found {}", burrow.depth, amphipod.to_char(), count)));
+        }
+    }
+
+    return Ok(burrow);
+}
+
+fn parse_spot(c: char, line_number: usize, column: usize) -> Result<Option<Amphipod>, Error> {
+    if c == '.' {
+        return Ok(None);
+    }
+
+    return match Amphipod::from_char(c) {
+        Some(amphipod) => Ok(Some(amphipod)),
+        None => Err(Error::parse(line_number, column, format!("expected an amphipod or '.', found '{}'", c))),
+    };
+}
+
+/// Inserts the two folded lines of the diagram, the rooms get a depth of 4.
+pub fn unfold(burrow: &Burrow) -> Burrow {
+    assert_eq!(burrow.depth, 2, "only a diagram with rooms of depth 2 can be unfolded");
+
+    let mut unfolded = *burrow;
+    unfolded.depth = 4;
+    for room in 0..ROOM_COUNT {
+        unfolded.rooms[room] = [burrow.rooms[room][0], Some(UNFOLDED_ROWS[0][room]), Some(UNFOLDED_ROWS[1][room]), burrow.rooms[room][1]];
+    }
+
+    return unfolded;
+}
+
+/// Returns the least energy that is needed to organize the amphipods, together with the moves to do so.
+pub fn organize(burrow: &Burrow) -> Option<(u64, Vec<Move>)> {
+    let path = search::a_star(
+        *burrow,
+        |state| state.moves().into_iter().map(|x| (state.apply(&x), x.energy)).collect::<Vec<(Burrow, u64)>>(),
+        |state| state.minimal_remaining_energy(),
+        |state| state.is_organized(),
+    )?;
+
+    // The search only knows the states, the move between two states is the one that leads from the first to the second
+    let moves = path.states.windows(2).map(|pair| pair[0].moves().into_iter().find(|x| pair[0].apply(x) == pair[1]).unwrap()).collect();
+    return Some((path.cost, moves));
+}
+
+#[test]
+fn test_day23_part1_example() {
+    let input = util::read_input_file("examples/day23/example.txt").unwrap();
+    let burrow = parse_burrow(&input).unwrap();
+    let (energy, moves) = organize(&burrow).unwrap();
+    assert_eq!(energy, 12521);
+
+    // Replaying the moves organizes the amphipods with the same energy
+    let organized = moves.iter().fold(burrow, |state, x| state.apply(x));
+    assert!(organized.is_organized());
+    assert_eq!(moves.iter().map(|x| x.energy).sum::<u64>(), 12521);
+}
+
+#[test]
+fn test_day23_part1_solution() {
+    let input = util::read_input_file("day23/input.txt").unwrap();
+    let burrow = parse_burrow(&input).unwrap();
+    let (energy, _) = organize(&burrow).unwrap();
+    assert_eq!(energy, 14346);
+}
+
+#[test]
+fn test_day23_part2_example() {
+    let input = util::read_input_file("examples/day23/example.txt").unwrap();
+    let burrow = unfold(&parse_burrow(&input).unwrap());
+    assert_eq!(burrow.to_string(), "#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########");
+
+    let (energy, moves) = organize(&burrow).unwrap();
+    assert_eq!(energy, 44169);
+    assert!(moves.iter().fold(burrow, |state, x| state.apply(x)).is_organized());
+}
+
+#[test]
+fn test_day23_part2_solution() {
+    let input = util::read_input_file("day23/input.txt").unwrap();
+    let burrow = unfold(&parse_burrow(&input).unwrap());
+    let (energy, _) = organize(&burrow).unwrap();
+    assert_eq!(energy, 48984);
+}
+
+#[test]
+fn test_day23_unsolvable_burrows() {
+    // D and A block each other in the hallway
+    let input = "#############\n#...D.A.....#\n###B#C#B#.###\n  #.#D#C#A#\n  #########\n";
+    assert!(organize(&parse_burrow(input).unwrap()).is_none());
+    assert_eq!(Day23::check(input).unwrap_err().to_string(), "expected an empty hallway, found D at column 5, A at column 7");
+
+    let input = "#############\n#...........#\n###A#A#D#B###\n  #C#C#D#B#\n  #########\n";
+    let burrow = Day23::parse(input).unwrap();
+    assert!(Day23::part1(&burrow).is_ok());
+    assert_eq!(Day23::part2(&burrow).unwrap_err().to_string(), "the amphipods can't be organized after unfolding the diagram");
+}
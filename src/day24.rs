@@ -0,0 +1,362 @@
+use std::fmt;
+
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub const MODEL_NUMBER_LENGTH: usize = 14;
+
+// Every digit is checked by the same block of instructions, only the three parameters marked with '?' differ
+pub const BLOCK_TEMPLATE: [&str; 18] = [
+    "inp w",
+    "mul x 0",
+    "add x z",
+    "mod x 26",
+    "div z ?",
+    "add x ?",
+    "eql x w",
+    "eql x 0",
+    "mul y 0",
+    "add y 25",
+    "mul y x",
+    "add y 1",
+    "mul z y",
+    "mul y 0",
+    "add y w",
+    "add y ?",
+    "mul y x",
+    "add z y",
+];
+
+// Indices of the parameters in a block: the divisor of z, the number added to check the digit and the offset that is pushed
+pub const DIVISOR_INDEX: usize = 4;
+pub const CHECK_INDEX: usize = 5;
+pub const OFFSET_INDEX: usize = 15;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Monad;
+
+    fn parse(input: &str) -> Result<Monad, Error> {
+        let program = parse_program(input)?;
+        let constraints = analyze_program(&program)?;
+        return Ok(Monad { program, constraints });
+    }
+
+    fn part1(monad: &Monad) -> Result<Answer, Error> {
+        return Ok(find_model_number(&monad.constraints, true).into());
+    }
+
+    fn part2(monad: &Monad) -> Result<Answer, Error> {
+        return Ok(find_model_number(&monad.constraints, false).into());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", ["w", "x", "y", "z"][*self as usize]);
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Number(number) => write!(f, "{}", number),
+        };
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Instruction::Inp(register) => write!(f, "inp {}", register),
+            Instruction::Add(register, operand) => write!(f, "add {} {}", register, operand),
+            Instruction::Mul(register, operand) => write!(f, "mul {} {}", register, operand),
+            Instruction::Div(register, operand) => write!(f, "div {} {}", register, operand),
+            Instruction::Mod(register, operand) => write!(f, "mod {} {}", register, operand),
+            Instruction::Eql(register, operand) => write!(f, "eql {} {}", register, operand),
+        };
+    }
+}
+
+/// Digit `digit` of a valid model number must equal digit `other` plus `difference`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraint {
+    pub digit: usize,
+    pub other: usize,
+    pub difference: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monad {
+    pub program: Vec<Instruction>,
+    pub constraints: Vec<Constraint>,
+}
+
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, Error> {
+    return util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line)| parse_instruction(line, *line_number)));
+}
+
+pub fn parse_instruction(line: &str, line_number: usize) -> Result<Instruction, Error> {
+    let parts = util::split_with_columns(line, " ");
+    let expected_parts = if parts[0].1 == "inp" { 2 } else { 3 };
+    if parts.len() != expected_parts {
+        return Err(Error::parse(line_number, 1, format!("expected {} parts in '{}', found {}", expected_parts, line, parts.len())));
+    }
+
+    let register = parse_register(parts[1].1).ok_or_else(|| Error::parse(line_number, parts[1].0, format!("expected a register, found '{}'", parts[1].1)))?;
+    if parts[0].1 == "inp" {
+        return Ok(Instruction::Inp(register));
+    }
+
+    let operand = match parse_register(parts[2].1) {
+        Some(register) => Operand::Register(register),
+        None => Operand::Number(util::parse_value::<i64>(parts[2].1, line_number, parts[2].0)?),
+    };
+
+    return match parts[0].1 {
+        "add" => Ok(Instruction::Add(register, operand)),
+        "mul" => Ok(Instruction::Mul(register, operand)),
+        "div" => Ok(Instruction::Div(register, operand)),
+        "mod" => Ok(Instruction::Mod(register, operand)),
+        "eql" => Ok(Instruction::Eql(register, operand)),
+        name => Err(Error::parse(line_number, 1, format!("unknown instruction '{}'", name))),
+    };
+}
+
+fn parse_register(name: &str) -> Option<Register> {
+    return match name {
+        "w" => Some(Register::W),
+        "x" => Some(Register::X),
+        "y" => Some(Register::Y),
+        "z" => Some(Register::Z),
+        _ => None,
+    };
+}
+
+/// Runs the program with the given inputs and returns the registers w, x, y and z.
+pub fn run_program(program: &Vec<Instruction>, inputs: &[i64]) -> Result<[i64; 4], Error> {
+    let mut registers = [0; 4];
+    let mut inputs = inputs.iter();
+
+    for (i, instruction) in program.iter().enumerate() {
+        let value = |operand: &Operand| match operand {
+            Operand::Register(register) => registers[*register as usize],
+            Operand::Number(number) => *number,
+        };
+
+        let (register, result) = match instruction {
+            Instruction::Inp(register) => {
+                let input = inputs.next().ok_or_else(|| Error::input(format!("instruction {} reads more inputs than given", i + 1)))?;
+                (register, *input)
+            },
+            Instruction::Add(register, operand) => {
+                let sum = registers[*register as usize].checked_add(value(operand));
+                (register, sum.ok_or_else(|| Error::input(format!("instruction {} overflows", i + 1)))?)
+            },
+            Instruction::Mul(register, operand) => {
+                let product = registers[*register as usize].checked_mul(value(operand));
+                (register, product.ok_or_else(|| Error::input(format!("instruction {} overflows", i + 1)))?)
+            },
+            Instruction::Div(register, operand) => {
+                if value(operand) == 0 {
+                    return Err(Error::input(format!("instruction {} divides by 0", i + 1)));
+                }
+                // Only the smallest number divided by -1 overflows
+                let quotient = registers[*register as usize].checked_div(value(operand));
+                (register, quotient.ok_or_else(|| Error::input(format!("instruction {} overflows", i + 1)))?)
+            },
+            Instruction::Mod(register, operand) => {
+                if registers[*register as usize] < 0 || value(operand) <= 0 {
+                    return Err(Error::input(format!("instruction {} calculates {} mod {}", i + 1, registers[*register as usize], value(operand))));
+                }
+                (register, registers[*register as usize] % value(operand))
+            },
+            Instruction::Eql(register, operand) => (register, (registers[*register as usize] == value(operand)) as i64),
+        };
+
+        registers[*register as usize] = result;
+    }
+
+    return Ok(registers);
+}
+
+/// Checks a model number with the interpreter: it must have 14 digits without zeros, and MONAD must end with z = 0.
+pub fn is_valid_model_number(program: &Vec<Instruction>, number: u64) -> bool {
+    let digits: Vec<i64> = number.to_string().chars().map(|c| c.to_digit(10).unwrap() as i64).collect();
+    if digits.len() != MODEL_NUMBER_LENGTH || digits.contains(&0) {
+        return false;
+    }
+
+    return match run_program(program, &digits) {
+        Ok(registers) => registers[Register::Z as usize] == 0,
+        Err(_) => false,
+    };
+}
+
+// Returns the parameter of a template line (0 if it has none), or None if the instruction doesn't match the template
+fn match_template(instruction: &Instruction, template: &str) -> Option<i64> {
+    let text = instruction.to_string();
+
+    return match template.strip_suffix('?') {
+        Some(prefix) => text.strip_prefix(prefix)?.parse::<i64>().ok(),
+        None => if text == template { Some(0) } else { None },
+    };
+}
+
+/// Splits MONAD into its 14 digit blocks and derives which digits depend on each other.
+///
+/// Every block treats z as a stack of base 26 numbers: a block that divides z by 1 pushes its digit plus its offset,
+/// a block that divides z by 26 pops the top and only avoids a new push if its digit equals the popped value plus its check.
+/// Each pop therefore ties its digit to the digit of the matching push, and z is 0 in the end if all of these hold.
+pub fn analyze_program(program: &Vec<Instruction>) -> Result<Vec<Constraint>, Error> {
+    if program.len() != MODEL_NUMBER_LENGTH * BLOCK_TEMPLATE.len() {
+        return Err(Error::input(format!("expected {} digit blocks of {} instructions, found {} instructions", MODEL_NUMBER_LENGTH, BLOCK_TEMPLATE.len(), program.len())));
+    }
+
+    let mut stack: Vec<(usize, i64)> = vec![];
+    let mut constraints: Vec<Constraint> = vec![];
+
+    for (digit, block) in program.chunks(BLOCK_TEMPLATE.len()).enumerate() {
+        let mut parameters: Vec<i64> = vec![];
+        for (i, instruction) in block.iter().enumerate() {
+            let parameter = match_template(instruction, BLOCK_TEMPLATE[i]).ok_or_else(|| {
+                Error::input(format!("instruction {} of block {} doesn't match the digit block of MONAD", i + 1, digit + 1))
+            })?;
+            parameters.push(parameter);
+        }
+
+        let check = parameters[CHECK_INDEX];
+        let offset = parameters[OFFSET_INDEX];
+        match parameters[DIVISOR_INDEX] {
+            1 if check > 9 => stack.push((digit, offset)),
+            26 => {
+                let (other, other_offset) = stack.pop().ok_or_else(|| Error::input(format!("block {} pops from an empty stack", digit + 1)))?;
+                constraints.push(Constraint { digit, other, difference: other_offset + check });
+            },
+            divisor => return Err(Error::input(format!("block {} divides by {} with a check of {}, expected 1 with a check above 9 or 26", digit + 1, divisor, check))),
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(Error::input(format!("{} pushed digits are never popped", stack.len())));
+    }
+
+    if let Some(constraint) = constraints.iter().find(|constraint| constraint.difference.abs() > 8) {
+        return Err(Error::input(format!("digits {} and {} can't differ by {}", constraint.other + 1, constraint.digit + 1, constraint.difference)));
+    }
+
+    return Ok(constraints);
+}
+
+/// Returns the largest (or the smallest) model number that satisfies all constraints.
+pub fn find_model_number(constraints: &Vec<Constraint>, largest: bool) -> u64 {
+    let mut digits = [0; MODEL_NUMBER_LENGTH];
+
+    // For every pair the digit that has to be larger is set to 9 (or the smaller one to 1) and the other follows
+    for constraint in constraints {
+        let (digit, other) = match (largest, constraint.difference >= 0) {
+            (true, true) => (9, 9 - constraint.difference),
+            (true, false) => (9 + constraint.difference, 9),
+            (false, true) => (1 + constraint.difference, 1),
+            (false, false) => (1, 1 - constraint.difference),
+        };
+        digits[constraint.digit] = digit;
+        digits[constraint.other] = other;
+    }
+
+    return digits.iter().fold(0, |number, digit| number * 10 + *digit as u64);
+}
+
+#[test]
+fn test_day24_part1_example() {
+    // Stores the lowest four bits of the input in w, x, y and z
+    let input = String::from("inp w
+add z w
+mod z 2
+div w 2
+add y w
+mod y 2
+div w 2
+add x w
+mod x 2
+div w 2
+mod w 2");
+    let program = parse_program(&input).unwrap();
+    assert_eq!(run_program(&program, &[13]).unwrap(), [1, 1, 0, 1]);
+    assert!(analyze_program(&program).is_err());
+}
+
+#[test]
+fn test_day24_part1_solution() {
+    let input = util::read_input_file("day24/input.txt").unwrap();
+    let program = parse_program(&input).unwrap();
+    let constraints = analyze_program(&program).unwrap();
+    let number = find_model_number(&constraints, true);
+
+    assert_eq!(number, 91939497397997);
+    assert!(is_valid_model_number(&program, number));
+    assert!(!is_valid_model_number(&program, number + 1));
+}
+
+#[test]
+fn test_day24_part2_example() {
+    // Returns 1 in z if the second input is three times the first one
+    let input = String::from("inp z
+inp x
+mul z 3
+eql z x");
+    let program = parse_program(&input).unwrap();
+    assert_eq!(run_program(&program, &[3, 9]).unwrap()[Register::Z as usize], 1);
+    assert_eq!(run_program(&program, &[3, 8]).unwrap()[Register::Z as usize], 0);
+    assert!(run_program(&program, &[3]).is_err());
+
+    let program = parse_program("inp z\nmul z 1000000\nmul z z\nmul z z").unwrap();
+    assert_eq!(run_program(&program, &[9]).unwrap_err().to_string(), "instruction 4 overflows");
+    let program = parse_program("inp z\nmul z -1\nadd z -9223372036854775807\nadd z -1").unwrap();
+    assert_eq!(run_program(&program, &[1]).unwrap_err().to_string(), "instruction 4 overflows");
+}
+
+#[test]
+fn test_day24_part2_solution() {
+    let input = util::read_input_file("day24/input.txt").unwrap();
+    let program = parse_program(&input).unwrap();
+    let constraints = analyze_program(&program).unwrap();
+    let number = find_model_number(&constraints, false);
+
+    assert_eq!(number, 31917161171331);
+    assert!(is_valid_model_number(&program, number));
+
+    // number - 1 ends in 0 and would be rejected before running the program, the next lower number without a 0 is not
+    let lower = (1..number).rev().find(|candidate| !candidate.to_string().contains('0')).unwrap();
+    let digits: Vec<i64> = lower.to_string().chars().map(|c| c.to_digit(10).unwrap() as i64).collect();
+    assert_eq!(lower, 31917161171329);
+    assert_ne!(run_program(&program, &digits).unwrap()[Register::Z as usize], 0);
+    assert!(!is_valid_model_number(&program, lower));
+}
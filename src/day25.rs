@@ -0,0 +1,134 @@
+use std::fmt;
+
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+use crate::util::Grid;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Grid<Tile>, Error> {
+        return parse_sea_floor(input);
+    }
+
+    fn part1(sea_floor: &Grid<Tile>) -> Result<Answer, Error> {
+        return Ok(count_steps_until_stopped(sea_floor, |_, _| {}).into());
+    }
+
+    // Day 25 only has one puzzle, the second star is given for all others
+    fn part2(_: &Grid<Tile>) -> Result<Answer, Error> {
+        return Ok(String::from("there is no second part on day 25").into());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tile {
+    Empty,
+    East,
+    South,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Tile::Empty => '.',
+            Tile::East => '>',
+            Tile::South => 'v',
+        };
+        return write!(f, "{}", c);
+    }
+}
+
+pub fn parse_sea_floor(input: &str) -> Result<Grid<Tile>, Error> {
+    let lines = util::numbered_lines(input);
+    if lines.is_empty() {
+        return Err(Error::input("the input is empty"));
+    }
+
+    return Grid::parse_chars(&lines, |c| match c {
+        '.' => Some(Tile::Empty),
+        '>' => Some(Tile::East),
+        'v' => Some(Tile::South),
+        _ => None,
+    });
+}
+
+// All sea cucumbers of the herd look at the same state first and then move at once.
+// Returns the new state and how many sea cucumbers moved.
+fn move_herd(sea_floor: &Grid<Tile>, herd: Tile, (dx, dy): (isize, isize)) -> (Grid<Tile>, usize) {
+    let mut moved_sea_floor = sea_floor.clone();
+    let mut moved = 0;
+
+    for (x, y) in sea_floor.positions().filter(|position| sea_floor[*position] == herd) {
+        let target = sea_floor.wrapping_offset(x, y, dx, dy);
+        if sea_floor[target] == Tile::Empty {
+            moved_sea_floor[(x, y)] = Tile::Empty;
+            moved_sea_floor[target] = herd;
+            moved += 1;
+        }
+    }
+
+    return (moved_sea_floor, moved);
+}
+
+/// Moves the east-facing herd and then the south-facing herd once, returns the new state and how many sea cucumbers moved.
+pub fn step(sea_floor: &Grid<Tile>) -> (Grid<Tile>, usize) {
+    let (sea_floor, moved_east) = move_herd(sea_floor, Tile::East, (1, 0));
+    let (sea_floor, moved_south) = move_herd(&sea_floor, Tile::South, (0, 1));
+    return (sea_floor, moved_east + moved_south);
+}
+
+/// Returns the first step on which no sea cucumber moves, `inspect` is called with every step number and the state after it.
+pub fn count_steps_until_stopped<F: FnMut(usize, &Grid<Tile>)>(sea_floor: &Grid<Tile>, mut inspect: F) -> usize {
+    let mut sea_floor = sea_floor.clone();
+    let mut steps = 0;
+
+    loop {
+        let (moved_sea_floor, moved) = step(&sea_floor);
+        steps += 1;
+        inspect(steps, &moved_sea_floor);
+
+        if moved == 0 {
+            return steps;
+        }
+        sea_floor = moved_sea_floor;
+    }
+}
+
+#[test]
+fn test_day25_part1_example() {
+    let input = util::read_input_file("examples/day25/example.txt").unwrap();
+    let sea_floor = parse_sea_floor(&input).unwrap();
+
+    let mut states: Vec<String> = vec![];
+    let steps = count_steps_until_stopped(&sea_floor, |_, state| states.push(state.to_string()));
+    assert_eq!(steps, 58);
+    assert_eq!(states[0], "....>.>v.>
+v.v>.>v.v.
+>v>>..>v..
+>>v>v>.>.v
+.>v.v...v.
+v>>.>vvv..
+..v...>>..
+vv...>>vv.
+>.v.v..v.v");
+    assert_eq!(states[56], states[57]);
+}
+
+#[test]
+fn test_day25_part1_solution() {
+    let input = util::read_input_file("day25/input.txt").unwrap();
+    let sea_floor = parse_sea_floor(&input).unwrap();
+    assert_eq!(count_steps_until_stopped(&sea_floor, |_, _| {}), 370);
+}
+
+#[test]
+fn test_day25_wrap_around() {
+    let sea_floor = parse_sea_floor("...>>\n.....\n.v...").unwrap();
+    let (moved_sea_floor, moved) = step(&sea_floor);
+    assert_eq!(moved, 2);
+    assert_eq!(moved_sea_floor.to_string(), ">v.>.\n.....\n.....");
+}
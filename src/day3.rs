@@ -1,21 +1,88 @@
-mod util;
+use std::collections::HashMap;
 
-fn main()
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+
+pub struct Day3;
+
+impl Solution for Day3
 {
-    let input = util::read_input_file("day3.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    
-    let columns = extract_all_bits_column_wise(&lines);
-    let gamma_rate = get_gamma_rate(&columns);
-    let epsilon_rate = get_epsilon_rate(&columns);
-    println!("Part 1: Solution={}", gamma_rate * epsilon_rate);
+    type Input = Vec<String>;
 
-    let oxygen_generator_rating = get_oxygen_generator_rating(&lines);
-    let co2_scrubber_rating = get_co2_scrubber_rating(&lines);
-    println!("Part 2: Solution={}", oxygen_generator_rating * &co2_scrubber_rating);
+    fn parse(input: &str) -> Result<Vec<String>, Error>
+    {
+        let numbered_lines = util::numbered_lines(input);
+        if numbered_lines.is_empty()
+        {
+            return Err(Error::input("the diagnostic report is empty"));
+        }
+
+        let width = numbered_lines[0].1.len();
+
+        // The bit criteria only end with a single number if all numbers are different
+        let mut first_line_numbers: HashMap<&str, usize> = HashMap::new();
+
+        return util::collect_results(numbered_lines.iter().map(|(line_number, line)|
+        {
+            let bits = validate_bits(line, *line_number, width)?;
+            if let Some(first_line_number) = first_line_numbers.get(line)
+            {
+                return Err(Error::parse(*line_number, 1, format!("{} is already on line {}", line, first_line_number)));
+            }
+
+            first_line_numbers.insert(line, *line_number);
+            return Ok(bits);
+        }));
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, Error>
+    {
+        let columns = extract_all_bits_column_wise(lines);
+        let gamma_rate = get_gamma_rate(&columns);
+        let epsilon_rate = get_epsilon_rate(&columns);
+
+        return Ok((gamma_rate * epsilon_rate).into());
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, Error>
+    {
+        let oxygen_generator_rating = get_oxygen_generator_rating(lines);
+        let co2_scrubber_rating = get_co2_scrubber_rating(lines);
+
+        return Ok((oxygen_generator_rating * co2_scrubber_rating).into());
+    }
+
+    fn values(lines: &Vec<String>) -> Vec<(String, Answer)>
+    {
+        let columns = extract_all_bits_column_wise(lines);
+
+        return vec![
+            (String::from("gamma"), get_gamma_rate(&columns).into()),
+            (String::from("epsilon"), get_epsilon_rate(&columns).into()),
+            (String::from("oxygen_generator_rating"), get_oxygen_generator_rating(lines).into()),
+            (String::from("co2_scrubber_rating"), get_co2_scrubber_rating(lines).into()),
+        ];
+    }
 }
 
-fn get_gamma_rate(columns: &Vec<String>) -> i32
+// All numbers need the same number of bits as the first one
+pub fn validate_bits(line: &str, line_number: usize, width: usize) -> Result<String, Error>
+{
+    if let Some(column) = line.find(|c| c != '0' && c != '1')
+    {
+        return Err(Error::parse(line_number, column + 1, "expected only '0' and '1'"));
+    }
+
+    if line.len() != width
+    {
+        return Err(Error::parse(line_number, 1, format!("expected {} bits, found {}", width, line.len())));
+    }
+
+    return Ok(line.to_string());
+}
+
+pub fn get_gamma_rate(columns: &Vec<String>) -> i32
 {
     let mut binary_string = String::from("");
 
@@ -28,7 +95,7 @@ fn get_gamma_rate(columns: &Vec<String>) -> i32
     return parse_binary_string_as_decimal_int(binary_string);
 }
 
-fn get_epsilon_rate(columns: &Vec<String>) -> i32
+pub fn get_epsilon_rate(columns: &Vec<String>) -> i32
 {
     let mut binary_string = String::from("");
 
@@ -41,37 +108,35 @@ fn get_epsilon_rate(columns: &Vec<String>) -> i32
     return parse_binary_string_as_decimal_int(binary_string);
 }
 
-fn get_oxygen_generator_rating(lines: &Vec<String>) -> i32
+pub fn get_oxygen_generator_rating(lines: &Vec<String>) -> i32
 {
-    let line = get_line_by_bit_criteria(&lines, false);
+    let line = get_line_by_bit_criteria(lines, false);
     return parse_binary_string_as_decimal_int(line);
 }
 
-fn get_co2_scrubber_rating(lines: &Vec<String>) -> i32
+pub fn get_co2_scrubber_rating(lines: &Vec<String>) -> i32
 {
-    let line = get_line_by_bit_criteria(&lines, true);
+    let line = get_line_by_bit_criteria(lines, true);
     return parse_binary_string_as_decimal_int(line);
 }
 
-fn get_line_by_bit_criteria(lines: &Vec<String>, invert_criteria: bool) -> String
+pub fn get_line_by_bit_criteria(lines: &Vec<String>, invert_criteria: bool) -> String
 {
     let line_length = lines[0].len();
-    let mut matrix = vec![vec!['0' as char; line_length]; lines.len()];
+    let mut matrix = vec![vec!['0'; line_length]; lines.len()];
 
     for i in 0..lines.len()
     {
         let characters: Vec<char> = lines[i].chars().collect();
-        for j in 0..characters.len()
-        {
-            matrix[i][j] = characters[j];
-        }
+        matrix[i][..characters.len()].copy_from_slice(&characters);
     }
 
     let mut column_idx = 0;
     let mut rows_to_iterate: Vec<usize> = (0..lines.len()).collect();
     assert_eq!(rows_to_iterate.last().unwrap(), &(lines.len()-1));
 
-    while rows_to_iterate.len() > 1
+    // Only identical numbers are left once all bits are used, any of them is the result
+    while rows_to_iterate.len() > 1 && column_idx < line_length
     {
         let mut column_string: String = String::from("");
 
@@ -93,23 +158,25 @@ fn get_line_by_bit_criteria(lines: &Vec<String>, invert_criteria: bool) -> Strin
             }
         }
 
-        rows_to_iterate = next_rows_to_iterate;
+        // If all remaining numbers have the same bit, none of them has the least common one and all are kept
+        if !next_rows_to_iterate.is_empty()
+        {
+            rows_to_iterate = next_rows_to_iterate;
+        }
         column_idx += 1;
     }
 
-    assert_eq!(rows_to_iterate.len(), 1);
-
     let line: String = lines[rows_to_iterate[0]].to_string();
 
     return line;
 }
 
-fn invert_bit(bit: char) -> char
+pub fn invert_bit(bit: char) -> char
 {
     return if bit == '0' { '1' } else { '0' };
 }
 
-fn extract_all_bits_column_wise(lines: &Vec<String>) -> Vec<String>
+pub fn extract_all_bits_column_wise(lines: &Vec<String>) -> Vec<String>
 {
     let num_of_columns = lines[0].len();
     let mut columns: Vec<String> = vec!["".to_string(); num_of_columns];
@@ -127,7 +194,7 @@ fn extract_all_bits_column_wise(lines: &Vec<String>) -> Vec<String>
     return columns;
 }
 
-fn get_most_common_bit(column: &String, equal_char: char) -> char
+pub fn get_most_common_bit(column: &String, equal_char: char) -> char
 {
     let mut zeros: i32 = 0;
     let mut ones: i32 = 0;
@@ -153,7 +220,7 @@ fn get_most_common_bit(column: &String, equal_char: char) -> char
     return if zeros > ones { '0' } else { '1' };
 }
 
-fn parse_binary_string_as_decimal_int(binary_string: String) -> i32
+pub fn parse_binary_string_as_decimal_int(binary_string: String) -> i32
 {
     return isize::from_str_radix(&binary_string, 2).unwrap() as i32;
 }
@@ -161,19 +228,7 @@ fn parse_binary_string_as_decimal_int(binary_string: String) -> i32
 #[test]
 fn test_day3_part1_example()
 {
-    let input = "
-00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010".to_string();
+    let input = util::read_input_file("examples/day3/example.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
 
     let columns = extract_all_bits_column_wise(&lines);
@@ -187,7 +242,7 @@ fn test_day3_part1_example()
 #[test]
 fn test_day3_part1_solution()
 {
-    let input = util::read_input_file("day3.txt");
+    let input = util::read_input_file("day3/input.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     
     let columns = extract_all_bits_column_wise(&lines);
@@ -200,19 +255,7 @@ fn test_day3_part1_solution()
 #[test]
 fn test_day3_part2_example()
 {
-    let input = "
-00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010".to_string();
+    let input = util::read_input_file("examples/day3/example.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
 
     let oxygen_generator_rating = get_oxygen_generator_rating(&lines);
@@ -225,11 +268,44 @@ fn test_day3_part2_example()
 #[test]
 fn test_day3_part2_solution()
 {
-    let input = util::read_input_file("day3.txt");
+    let input = util::read_input_file("day3/input.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     
     let oxygen_generator_rating = get_oxygen_generator_rating(&lines);
     let co2_scrubber_rating = get_co2_scrubber_rating(&lines);
 
     assert_eq!(oxygen_generator_rating * co2_scrubber_rating, 1370737);
+}
+
+#[test]
+fn test_day3_check_reports_all_problems()
+{
+    let error = Day3::check("101\n1x1\n101\n11\n101\n").unwrap_err();
+    let problems: Vec<String> = error.problems().iter().map(|problem| problem.to_string()).collect();
+
+    assert_eq!(problems, vec![
+        "line 2, column 2: expected only '0' and '1'",
+        "line 3, column 1: 101 is already on line 1",
+        "line 4, column 1: expected 3 bits, found 2",
+        "line 5, column 1: 101 is already on line 1",
+    ]);
+}
+
+#[test]
+fn test_day3_duplicate_numbers()
+{
+    let lines = vec![String::from("101"), String::from("101"), String::from("011")];
+
+    assert_eq!(get_oxygen_generator_rating(&lines), 5);
+    assert_eq!(get_co2_scrubber_rating(&lines), 3);
+    assert_eq!(get_co2_scrubber_rating(&vec![String::from("101"), String::from("101")]), 5);
+}
+
+#[test]
+fn test_day3_values_only_when_requested()
+{
+    let input = util::read_input_file("examples/day3/example.txt").unwrap();
+
+    assert!(crate::run::<Day3>(&input, &[1], false).unwrap().values.is_empty());
+    assert_eq!(crate::run::<Day3>(&input, &[], true).unwrap().values.len(), 4);
 }
\ No newline at end of file
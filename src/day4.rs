@@ -1,6 +1,8 @@
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-trait BingoLogicPublic
+pub trait BingoLogicPublic
 {
     fn add_number(&mut self, number: i32);
     fn sum_unmarked_numbers(&self) -> i32;
@@ -11,12 +13,13 @@ trait BingoLogicPrivate
     fn check_win_condition(&mut self, row: usize, column: usize) -> bool;
 }
 
-struct BingoBoard
+#[derive(Clone)]
+pub struct BingoBoard
 {
-    size: usize,
-    numbers: Vec<Vec<i32>>,
-    is_number_drawn: Vec<Vec<bool>>,
-    has_won: bool,
+    pub size: usize,
+    pub numbers: Vec<Vec<i32>>,
+    pub is_number_drawn: Vec<Vec<bool>>,
+    pub has_won: bool,
 }
 
 impl BingoLogicPublic for BingoBoard
@@ -89,29 +92,33 @@ impl BingoLogicPrivate for BingoBoard
     }
 }
 
-fn main()
-{
-    let input = util::read_input_file("day4.txt");
-    let parts = util::split_string_by_string(&input, "\n\n");
+pub struct Day4;
 
-    let score = get_first_winning_score(&parts);
-    println!("Part 1: Solution={}", score);
+impl Solution for Day4
+{
+    type Input = (Vec<i32>, Vec<BingoBoard>);
 
-    let last_score = get_last_winning_score(&parts);
-    println!("Part 2: Solution={}", last_score);
-}
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<BingoBoard>), Error>
+    {
+        return parse_bingo_boards(input);
+    }
 
-fn get_first_winning_score(parts: &Vec<String>) -> i32
-{
-    let (drawn_numbers, mut bingo_boards) = parse_bingo_boards(&parts);
+    fn part1((drawn_numbers, bingo_boards): &(Vec<i32>, Vec<BingoBoard>)) -> Result<Answer, Error>
+    {
+        return Ok(get_first_winning_score(drawn_numbers, bingo_boards).into());
+    }
 
-    for i in 1..parts.len()
+    fn part2((drawn_numbers, bingo_boards): &(Vec<i32>, Vec<BingoBoard>)) -> Result<Answer, Error>
     {
-        let bingo_board = create_bingo_board(&parts[i]);
-        bingo_boards.push(bingo_board);
+        return Ok(get_last_winning_score(drawn_numbers, bingo_boards).into());
     }
+}
+
+pub fn get_first_winning_score(drawn_numbers: &Vec<i32>, bingo_boards: &Vec<BingoBoard>) -> i32
+{
+    let mut bingo_boards = bingo_boards.clone();
 
-    for drawn_number in drawn_numbers
+    for drawn_number in drawn_numbers.iter().copied()
     {
         for bingo_board in bingo_boards.iter_mut()
         {
@@ -127,22 +134,16 @@ fn get_first_winning_score(parts: &Vec<String>) -> i32
     return 0;
 }
 
-fn get_last_winning_score(parts: &Vec<String>) -> i32
+pub fn get_last_winning_score(drawn_numbers: &Vec<i32>, bingo_boards: &Vec<BingoBoard>) -> i32
 {
-    let (drawn_numbers, mut bingo_boards) = parse_bingo_boards(&parts);
-
-    for i in 1..parts.len()
-    {
-        let bingo_board = create_bingo_board(&parts[i]);
-        bingo_boards.push(bingo_board);
-    }
+    let mut bingo_boards = bingo_boards.clone();
 
-    for drawn_number in drawn_numbers
+    for drawn_number in drawn_numbers.iter().copied()
     {
         let mut number_of_boards = bingo_boards.len();
         let mut indexes_to_remove: Vec<usize> = vec![];
 
-        for i in 0..number_of_boards
+        for i in 0..bingo_boards.len()
         {
             let bingo_board = &mut bingo_boards[i];
             bingo_board.add_number(drawn_number);
@@ -167,70 +168,62 @@ fn get_last_winning_score(parts: &Vec<String>) -> i32
     return 0;
 }
 
-fn parse_bingo_boards(parts: &Vec<String>) -> (Vec<i32>, Vec<BingoBoard>)
+pub fn parse_bingo_boards(input: &str) -> Result<(Vec<i32>, Vec<BingoBoard>), Error>
 {
-    let drawn_number_strings = util::split_string_by_string(&parts[0], ",");
-    let drawn_numbers = util::parse_strings_as_ints(&drawn_number_strings);
+    let blocks = util::numbered_blocks(input);
+    if blocks.is_empty()
+    {
+        return Err(Error::input("missing the line with the drawn numbers"));
+    }
 
-    let mut bingo_boards: Vec<BingoBoard> = vec![];
+    let (line_number, line) = blocks[0][0];
+    let drawn_numbers = util::parse_separated::<i32>(line, ",", line_number, 1);
 
-    for i in 1..parts.len()
+    let mut errors: Vec<Error> = vec![];
+    if blocks[0].len() > 1
     {
-        let bingo_board = create_bingo_board(&parts[i]);
-        bingo_boards.push(bingo_board);
+        errors.push(Error::parse(blocks[0][1].0, 1, "expected a blank line between the drawn numbers and the first bingo board"));
     }
 
-    return (drawn_numbers, bingo_boards);
+    let bingo_boards = util::collect_results(blocks[1..].iter().map(|block| create_bingo_board(block)));
+
+    return match (drawn_numbers, bingo_boards)
+    {
+        (Ok(drawn_numbers), Ok(bingo_boards)) if errors.is_empty() => Ok((drawn_numbers, bingo_boards)),
+        (drawn_numbers, bingo_boards) =>
+        {
+            errors.extend(drawn_numbers.err());
+            errors.extend(bingo_boards.err());
+            Err(Error::combine(errors))
+        },
+    };
 }
 
-fn create_bingo_board(board_string: &String) -> BingoBoard
+pub fn create_bingo_board(lines: &[(usize, &str)]) -> Result<BingoBoard, Error>
 {
-    let lines = util::split_string_by_string(board_string, "\n");
-
     let size: usize = lines.len();
-    let mut numbers: Vec<Vec<i32>> = vec![vec![0; size]; size];
     let is_number_drawn: Vec<Vec<bool>> = vec![vec![false; size]; size];
 
-    for i in 0..size
-    {
-        let number_strings = util::split_string_by_string(&lines[i], " ");
-        assert_eq!(number_strings.len(), size);
-
-        for j in 0..size
+    let numbers = util::collect_results(lines.iter().map(|(line_number, line)| {
+        let number_strings = util::split_with_columns(line, " ");
+        if number_strings.len() != size
         {
-            numbers[i][j] = number_strings[j].parse::<i32>().unwrap();
+            return Err(Error::parse(*line_number, 1, format!("expected {} numbers in this row of a {}x{} bingo board, found {}", size, size, size, number_strings.len())));
         }
-    }
 
-    return BingoBoard { size: size, numbers: numbers, is_number_drawn: is_number_drawn, has_won: false };
+        return util::collect_results(number_strings.iter().map(|(column, number_string)| util::parse_value::<i32>(number_string, *line_number, *column)));
+    }))?;
+
+    return Ok(BingoBoard { size: size, numbers: numbers, is_number_drawn: is_number_drawn, has_won: false });
 }
 
 #[test]
 fn test_day4_part1_example()
 {
-    let input = "
-7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
-8  2 23  4 24
-21  9 14 16  7
-6 10  3 18  5
-1 12 20 15 19
-
-3 15  0  2 22
-9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
-2  0 12  3  7".to_string();
-    let parts = util::split_string_by_string(&input, "\n\n");
-
-    let score = get_first_winning_score(&parts);
+    let input = util::read_input_file("examples/day4/example.txt").unwrap();
+    let (drawn_numbers, bingo_boards) = parse_bingo_boards(&input).unwrap();
+
+    let score = get_first_winning_score(&drawn_numbers, &bingo_boards);
 
     assert_eq!(score, 4512)
 }
@@ -238,10 +231,10 @@ fn test_day4_part1_example()
 #[test]
 fn test_day4_part1_solution()
 {
-    let input = util::read_input_file("day4.txt");
-    let parts = util::split_string_by_string(&input, "\n\n");
+    let input = util::read_input_file("day4/input.txt").unwrap();
+    let (drawn_numbers, bingo_boards) = parse_bingo_boards(&input).unwrap();
 
-    let score = get_first_winning_score(&parts);
+    let score = get_first_winning_score(&drawn_numbers, &bingo_boards);
 
     assert_eq!(score, 49860);
 }
@@ -249,29 +242,10 @@ fn test_day4_part1_solution()
 #[test]
 fn test_day4_part2_example()
 {
-    let input = "
-7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
-8  2 23  4 24
-21  9 14 16  7
-6 10  3 18  5
-1 12 20 15 19
-
-3 15  0  2 22
-9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
-2  0 12  3  7".to_string();
-    let parts = util::split_string_by_string(&input, "\n\n");
-
-    let score = get_last_winning_score(&parts);
+    let input = util::read_input_file("examples/day4/example.txt").unwrap();
+    let (drawn_numbers, bingo_boards) = parse_bingo_boards(&input).unwrap();
+
+    let score = get_last_winning_score(&drawn_numbers, &bingo_boards);
 
     assert_eq!(score, 1924);
 }
@@ -279,10 +253,10 @@ fn test_day4_part2_example()
 #[test]
 fn test_day4_part2_solution()
 {
-    let input = util::read_input_file("day4.txt");
-    let parts = util::split_string_by_string(&input, "\n\n");
+    let input = util::read_input_file("day4/input.txt").unwrap();
+    let (drawn_numbers, bingo_boards) = parse_bingo_boards(&input).unwrap();
 
-    let score = get_last_winning_score(&parts);
+    let score = get_last_winning_score(&drawn_numbers, &bingo_boards);
 
     assert_eq!(score, 24628);
 }
\ No newline at end of file
@@ -1,12 +1,19 @@
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
+use crate::util::Grid;
 
-struct Point 
+// The vents of the puzzle are in 0..=999, larger coordinates would make the canvas huge
+pub const MAX_COORDINATE: i32 = 999;
+
+#[derive(Debug)]
+pub struct Point
 {
-    x: i32,
-    y: i32,
+    pub x: i32,
+    pub y: i32,
 }
 
-trait LineLogic
+pub trait LineLogic
 {
     fn is_horizontal(&self) -> bool;
     fn is_vertical(&self) -> bool;
@@ -14,10 +21,11 @@ trait LineLogic
     fn get_all_points(&self) -> Vec<Point>;
 }
 
-struct Line
+#[derive(Debug)]
+pub struct Line
 {
-    start: Point,
-    end: Point,
+    pub start: Point,
+    pub end: Point,
 }
 
 impl LineLogic for Line
@@ -64,46 +72,82 @@ impl LineLogic for Line
     }
 }
 
-fn main()
+pub struct Day5;
+
+impl Solution for Day5
 {
-    let input = util::read_input_file("day5.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let parsed_lines = parse_lines(&lines);
-    let canvas = draw_lines_on_canvas(&parsed_lines, 1000, true);
-    let count = count_values_greater(&canvas, 1);
+    type Input = Vec<Line>;
 
-    println!("Part 1: Solution={}", count);
+    fn parse(input: &str) -> Result<Vec<Line>, Error>
+    {
+        return parse_lines(input);
+    }
 
-    let canvas_with_diagonal = draw_lines_on_canvas(&parsed_lines, 1000, false);
-    let count_with_diagonal = count_values_greater(&canvas_with_diagonal, 1);
+    fn part1(parsed_lines: &Vec<Line>) -> Result<Answer, Error>
+    {
+        let canvas = draw_lines_on_canvas(parsed_lines, true);
+        return Ok(count_values_greater(&canvas, 1).into());
+    }
 
-    println!("Part 2: Solution={}", count_with_diagonal);
+    fn part2(parsed_lines: &Vec<Line>) -> Result<Answer, Error>
+    {
+        let canvas_with_diagonal = draw_lines_on_canvas(parsed_lines, false);
+        return Ok(count_values_greater(&canvas_with_diagonal, 1).into());
+    }
 }
 
-fn parse_lines(lines: &Vec<String>) -> Vec<Line>
+pub fn parse_lines(input: &str) -> Result<Vec<Line>, Error>
 {
-    let mut parsed_lines: Vec<Line> = vec![];
+    return util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line_string)| parse_line(line_string, *line_number)));
+}
 
-    for line_string in lines
+pub fn parse_line(line_string: &str, line_number: usize) -> Result<Line, Error>
+{
+    let parts = util::split_with_columns(line_string, "->");
+    if parts.len() != 2
     {
-        let parts = util::split_string_by_string(&line_string, " -> ");
-        assert_eq!(parts.len(), 2);
+        return Err(Error::parse(line_number, 1, format!("expected 'x1,y1 -> x2,y2', found '{}'", line_string)));
+    }
+
+    let mut points = util::collect_results(parts.iter().map(|(column, text)| parse_point(text, line_number, *column)))?;
+    let end_point = points.pop().unwrap();
+    let start_point = points.pop().unwrap();
+    let parsed_line = Line { start: start_point, end: end_point };
 
-        let start_coordinates = util::split_ints_by_string(&parts[0], ",");
-        let end_coordinates = util::split_ints_by_string(&parts[1], ",");
+    if parsed_line.is_diagonal() && (parsed_line.end.x - parsed_line.start.x).abs() != (parsed_line.end.y - parsed_line.start.y).abs()
+    {
+        return Err(Error::parse(line_number, 1, "lines must be horizontal, vertical or diagonal at exactly 45 degrees"));
+    }
 
-        let start_point = Point { x: start_coordinates[0], y: start_coordinates[1] };
-        let end_point = Point { x: end_coordinates[0], y: end_coordinates[1] };
-        let parsed_line = Line { start: start_point, end: end_point };
-        parsed_lines.push(parsed_line);
+    return Ok(parsed_line);
+}
+
+fn parse_point(text: &str, line_number: usize, column: usize) -> Result<Point, Error>
+{
+    let coordinates = util::split_with_columns(text, ",");
+    if coordinates.len() != 2
+    {
+        return Err(Error::parse(line_number, column, format!("expected a point 'x,y', found '{}'", text)));
     }
 
-    return parsed_lines;
+    let values = util::collect_results(coordinates.iter().map(|(offset, coordinate)| {
+        let value = util::parse_value::<i32>(coordinate, line_number, column + offset - 1)?;
+        if !(0..=MAX_COORDINATE).contains(&value)
+        {
+            return Err(Error::parse(line_number, column + offset - 1, format!("coordinate {} is outside of 0..{}", value, MAX_COORDINATE)));
+        }
+        return Ok(value);
+    }))?;
+
+    return Ok(Point { x: values[0], y: values[1] });
 }
 
-fn draw_lines_on_canvas(lines: &Vec<Line>, size: usize, ignore_diagonal: bool) -> Vec<Vec<i32>>
+// The canvas is just large enough for all lines, the coordinates are at most MAX_COORDINATE
+pub fn draw_lines_on_canvas(lines: &Vec<Line>, ignore_diagonal: bool) -> Grid<i32>
 {
-    let mut canvas = vec![vec![0; size]; size];
+    let width = lines.iter().map(|line| line.start.x.max(line.end.x) + 1).max().unwrap_or(0) as usize;
+    let height = lines.iter().map(|line| line.start.y.max(line.end.y) + 1).max().unwrap_or(0) as usize;
+    let mut canvas = Grid::new(width, height, 0);
 
     for line in lines
     {
@@ -115,48 +159,24 @@ fn draw_lines_on_canvas(lines: &Vec<Line>, size: usize, ignore_diagonal: bool) -
         let points = line.get_all_points();
         for point in points
         {
-            canvas[point.x as usize][point.y as usize] += 1;
+            canvas[(point.x as usize, point.y as usize)] += 1;
         }
     }
 
     return canvas;
 }
 
-fn count_values_greater(canvas: &Vec<Vec<i32>>, greater_as: i32) -> i32
+pub fn count_values_greater(canvas: &Grid<i32>, greater_as: i32) -> i32
 {
-    let mut count = 0;
-
-    for x in 0..canvas.len()
-    {
-        for y in 0..canvas[x].len()
-        {
-            if canvas[x][y] > greater_as
-            {
-                count += 1;
-            }
-        }
-    }
-
-    return count;
+    return canvas.iter().filter(|value| **value > greater_as).count() as i32;
 }
 
 #[test]
 fn test_day5_part1_example()
 {
-    let input = "
-0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let parsed_lines = parse_lines(&lines);
-    let canvas = draw_lines_on_canvas(&parsed_lines, 10, true);
+    let input = util::read_input_file("examples/day5/example.txt").unwrap();
+    let parsed_lines = parse_lines(&input).unwrap();
+    let canvas = draw_lines_on_canvas(&parsed_lines, true);
     let count = count_values_greater(&canvas, 1);
 
     assert_eq!(count, 5);
@@ -165,10 +185,9 @@ fn test_day5_part1_example()
 #[test]
 fn test_day5_part1_solution()
 {
-    let input = util::read_input_file("day5.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let parsed_lines = parse_lines(&lines);
-    let canvas = draw_lines_on_canvas(&parsed_lines, 1000, true);
+    let input = util::read_input_file("day5/input.txt").unwrap();
+    let parsed_lines = parse_lines(&input).unwrap();
+    let canvas = draw_lines_on_canvas(&parsed_lines, true);
     let count = count_values_greater(&canvas, 1);
 
     assert_eq!(count, 5632);
@@ -177,20 +196,9 @@ fn test_day5_part1_solution()
 #[test]
 fn test_day5_part2_example()
 {
-    let input = "
-0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2".to_string();
-    let lines = util::split_string_by_string(&input, "\n");
-    let parsed_lines = parse_lines(&lines);
-    let canvas = draw_lines_on_canvas(&parsed_lines, 10, false);
+    let input = util::read_input_file("examples/day5/example.txt").unwrap();
+    let parsed_lines = parse_lines(&input).unwrap();
+    let canvas = draw_lines_on_canvas(&parsed_lines, false);
     let count = count_values_greater(&canvas, 1);
 
     assert_eq!(count, 12);
@@ -199,11 +207,35 @@ fn test_day5_part2_example()
 #[test]
 fn test_day5_part2_solution()
 {
-    let input = util::read_input_file("day5.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    let parsed_lines = parse_lines(&lines);
-    let canvas = draw_lines_on_canvas(&parsed_lines, 1000, false);
+    let input = util::read_input_file("day5/input.txt").unwrap();
+    let parsed_lines = parse_lines(&input).unwrap();
+    let canvas = draw_lines_on_canvas(&parsed_lines, false);
     let count = count_values_greater(&canvas, 1);
 
     assert_eq!(count, 22213);
+}
+
+#[test]
+fn test_day5_coordinates_out_of_range()
+{
+    let error = parse_line("0,0 -> 2147483647,0", 3).unwrap_err();
+    assert_eq!(error.to_string(), "line 3, column 8: coordinate 2147483647 is outside of 0..999");
+
+    let error = parse_line("0,1000 -> 5,-1", 1).unwrap_err();
+    assert_eq!(error.to_string(), "line 1, column 3: coordinate 1000 is outside of 0..999\nline 1, column 13: coordinate -1 is outside of 0..999");
+
+    assert!(parse_line("999,0 -> 0,999", 1).is_ok());
+}
+
+#[test]
+fn test_day5_check_reports_all_problems()
+{
+    let error = Day5::check("0,9 -> 5,9\n0,0 -> 2147483647,0\n8,0 -> 0,8\n1,1 -> 3,4\n9,4 -> 3,x\n").unwrap_err();
+    let problems: Vec<String> = error.problems().iter().map(|problem| problem.to_string()).collect();
+
+    assert_eq!(problems, vec![
+        "line 2, column 8: coordinate 2147483647 is outside of 0..999",
+        "line 4, column 1: lines must be horizontal, vertical or diagonal at exactly 45 degrees",
+        "line 5, column 10: invalid number 'x'",
+    ]);
 }
\ No newline at end of file
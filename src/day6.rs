@@ -1,18 +1,53 @@
-mod util;
+use crate::error::Error;
+#[cfg(test)]
+use crate::generate;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main()
+pub struct Day6;
+
+impl Solution for Day6
 {
-    let input = util::read_input_file("day6.txt");
-    let fishes = util::split_generics_by_string::<i32>(&input, ",");
-    let fish_lives = parse_fish_lives(&fishes);
-    let fishes_after_80_days = simulate_days(&fish_lives, 80);
-    let fishes_after_256_days = simulate_days(&fish_lives, 256);
-    
-    println!("Part 1: Solution={}", fishes_after_80_days);
-    println!("Part 2: Solution={}", fishes_after_256_days);
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, Error>
+    {
+        let lines = util::numbered_lines(input);
+        if lines.len() != 1
+        {
+            return Err(Error::input("expected a single line of comma separated timers"));
+        }
+
+        let (line_number, line) = lines[0];
+        let fishes = util::collect_results(util::split_with_columns(line, ",").iter().map(|(column, timer)| {
+            let value = util::parse_value::<i32>(timer, line_number, *column)?;
+            if !(0..=8).contains(&value)
+            {
+                return Err(Error::parse(line_number, *column, format!("timer {} is outside of 0..8", value)));
+            }
+            return Ok(value);
+        }))?;
+
+        return Ok(parse_fish_lives(&fishes));
+    }
+
+    fn part1(fish_lives: &Vec<u64>) -> Result<Answer, Error>
+    {
+        return Ok(simulate_days(fish_lives, 80).into());
+    }
+
+    fn part2(fish_lives: &Vec<u64>) -> Result<Answer, Error>
+    {
+        return Ok(simulate_days(fish_lives, 256).into());
+    }
+
+    fn values(fish_lives: &Vec<u64>) -> Vec<(String, Answer)>
+    {
+        return vec![(String::from("fishes_after_18_days"), simulate_days(fish_lives, 18).into())];
+    }
 }
 
-fn parse_fish_lives(fishes: &Vec<i32>) -> Vec<u64>
+pub fn parse_fish_lives(fishes: &Vec<i32>) -> Vec<u64>
 {
     let mut fish_lives: Vec<u64> = vec![0; 9];
 
@@ -24,7 +59,7 @@ fn parse_fish_lives(fishes: &Vec<i32>) -> Vec<u64>
     return fish_lives;
 }
 
-fn simulate_days(fish_lives: &Vec<u64>, simulate_days: i32) -> u64
+pub fn simulate_days(fish_lives: &Vec<u64>, simulate_days: i32) -> u64
 {
     // Use a 9-element array with one position for each possible value of 'internal timer'.
     // For each day, move all fishes from first position to last position (child fishes) and add the same number to index 6 (reset parents)
@@ -41,10 +76,37 @@ fn simulate_days(fish_lives: &Vec<u64>, simulate_days: i32) -> u64
     return sum_of_fishes;
 }
 
+// Simulates every single fish instead of counting the fishes per timer, only feasible for a few fishes and days
+pub fn simulate_fishes_individually(fishes: &Vec<i32>, simulate_days: i32) -> u64
+{
+    let mut timers = fishes.to_vec();
+
+    for _ in 0..simulate_days
+    {
+        let mut new_fishes = 0;
+        for timer in timers.iter_mut()
+        {
+            if *timer == 0
+            {
+                *timer = 6;
+                new_fishes += 1;
+            }
+            else
+            {
+                *timer -= 1;
+            }
+        }
+
+        timers.extend(vec![8; new_fishes]);
+    }
+
+    return timers.len() as u64;
+}
+
 #[test]
 fn test_day6_part1_example()
 {
-    let input = "3,4,3,1,2".to_string();
+    let input = util::read_input_file("examples/day6/example.txt").unwrap();
     let fishes = util::split_generics_by_string::<i32>(&input, ",");
     let fish_lives = parse_fish_lives(&fishes);
     let fishes_after_18_days = simulate_days(&fish_lives, 18);
@@ -57,7 +119,7 @@ fn test_day6_part1_example()
 #[test]
 fn test_day6_part1_solution()
 {
-    let input = util::read_input_file("day6.txt");
+    let input = util::read_input_file("day6/input.txt").unwrap();
     let fishes = util::split_generics_by_string::<i32>(&input, ",");
     let fish_lives = parse_fish_lives(&fishes);
     let fishes_after_80_days = simulate_days(&fish_lives, 80);
@@ -68,7 +130,7 @@ fn test_day6_part1_solution()
 #[test]
 fn test_day6_part2_example()
 {
-    let input = "3,4,3,1,2".to_string();
+    let input = util::read_input_file("examples/day6/example.txt").unwrap();
     let fishes = util::split_generics_by_string::<i32>(&input, ",");
     let fish_lives = parse_fish_lives(&fishes);
     let fishes_after_256_days = simulate_days(&fish_lives, 256);
@@ -79,10 +141,23 @@ fn test_day6_part2_example()
 #[test]
 fn test_day6_part2_solution()
 {
-    let input = util::read_input_file("day6.txt");
+    let input = util::read_input_file("day6/input.txt").unwrap();
     let fishes = util::split_generics_by_string::<i32>(&input, ",");
     let fish_lives = parse_fish_lives(&fishes);
     let fishes_after_256_days = simulate_days(&fish_lives, 256);
 
     assert_eq!(fishes_after_256_days, 1600306001288);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_day6_part1_reference()
+{
+    let input = util::read_input_file("examples/day6/example.txt").unwrap();
+    assert_eq!(simulate_fishes_individually(&util::split_generics_by_string::<i32>(&input, ","), 18), 26);
+
+    let solve = |input: &str| simulate_days(&Day6::parse(input).unwrap(), 80);
+    let reference = |input: &str| simulate_fishes_individually(&util::split_generics_by_string::<i32>(input, ","), 80);
+
+    let counterexample = generate::find_counterexample(6, &[1, 2, 5], 20, solve, reference);
+    assert!(counterexample.is_none(), "{:?}", counterexample);
+}
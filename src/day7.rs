@@ -1,26 +1,54 @@
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main()
+pub struct Day7;
+
+impl Solution for Day7
 {
-    let input = util::read_input_file("day7.txt");
-    let positions = util::split_generics_by_string::<i32>(&input, ",");
+    type Input = Vec<i32>;
 
-    let (_, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(&positions, true);
-    println!("Part 1: Solution={}", best_fuel_consumption);
+    fn parse(input: &str) -> Result<Vec<i32>, Error>
+    {
+        let lines = util::numbered_lines(input);
+        if lines.len() != 1
+        {
+            return Err(Error::input("expected a single line of comma separated positions"));
+        }
 
-    let (_, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(&positions, false);
-    println!("Part 2: Solution={}", best_fuel_consumption);
+        let (line_number, line) = lines[0];
+        return util::collect_results(util::split_with_columns(line, ",").iter().map(|(column, position)| {
+            let value = util::parse_value::<i32>(position, line_number, *column)?;
+            if value < 0
+            {
+                return Err(Error::parse(line_number, *column, "positions must not be negative"));
+            }
+            return Ok(value);
+        }));
+    }
+
+    fn part1(positions: &Vec<i32>) -> Result<Answer, Error>
+    {
+        let (_, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(positions, true);
+        return Ok(best_fuel_consumption.into());
+    }
+
+    fn part2(positions: &Vec<i32>) -> Result<Answer, Error>
+    {
+        let (_, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(positions, false);
+        return Ok(best_fuel_consumption.into());
+    }
 }
 
-fn get_position_with_lowest_fuel_consumption(positions: &Vec<i32>, constant_fuel_consumption: bool) -> (i32, i32)
+pub fn get_position_with_lowest_fuel_consumption(positions: &Vec<i32>, constant_fuel_consumption: bool) -> (i32, i64)
 {
     let mut best_position:i32 = -1;
-    let mut best_fuel_consumption:i32 = i32::MAX;
+    let mut best_fuel_consumption:i64 = i64::MAX;
 
     let max_position = *positions.iter().max().unwrap();
     for position in 0..=max_position
     {
-        let fuel_consumption = get_fuel_consumption_for_position(&positions, position, constant_fuel_consumption);
+        let fuel_consumption = get_fuel_consumption_for_position(positions, position, constant_fuel_consumption);
         if fuel_consumption < best_fuel_consumption
         {
             best_position = position;
@@ -31,13 +59,13 @@ fn get_position_with_lowest_fuel_consumption(positions: &Vec<i32>, constant_fuel
     return (best_position, best_fuel_consumption);
 }
 
-fn get_fuel_consumption_for_position(positions: &Vec<i32>, alignment_position: i32, constant_fuel_consumption: bool) -> i32
+pub fn get_fuel_consumption_for_position(positions: &Vec<i32>, alignment_position: i32, constant_fuel_consumption: bool) -> i64
 {
-    let mut fuel_consumption: i32 = 0;
+    let mut fuel_consumption: i64 = 0;
 
     for position in positions
     {
-        let distance = if position >= &alignment_position { position - alignment_position } else { alignment_position - position };
+        let distance = (*position as i64 - alignment_position as i64).abs();
         if constant_fuel_consumption
         {
             fuel_consumption += distance;
@@ -54,7 +82,7 @@ fn get_fuel_consumption_for_position(positions: &Vec<i32>, alignment_position: i
 #[test]
 fn test_day7_part1_example()
 {
-    let input = "16,1,2,0,4,2,7,1,2,14".to_string();
+    let input = util::read_input_file("examples/day7/example.txt").unwrap();
     let positions = util::split_generics_by_string::<i32>(&input, ",");
     let (best_position, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(&positions, true);
 
@@ -65,7 +93,7 @@ fn test_day7_part1_example()
 #[test]
 fn test_day7_part1_solution()
 {
-    let input = util::read_input_file("day7.txt");
+    let input = util::read_input_file("day7/input.txt").unwrap();
     let positions = util::split_generics_by_string::<i32>(&input, ",");
     let (best_position, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(&positions, true);
 
@@ -76,7 +104,7 @@ fn test_day7_part1_solution()
 #[test]
 fn test_day7_part2_example()
 {
-    let input = "16,1,2,0,4,2,7,1,2,14".to_string();
+    let input = util::read_input_file("examples/day7/example.txt").unwrap();
     let positions = util::split_generics_by_string::<i32>(&input, ",");
     let (best_position, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(&positions, false);
 
@@ -87,10 +115,19 @@ fn test_day7_part2_example()
 #[test]
 fn test_day7_part2_solution()
 {
-    let input = util::read_input_file("day7.txt");
+    let input = util::read_input_file("day7/input.txt").unwrap();
     let positions = util::split_generics_by_string::<i32>(&input, ",");
     let (best_position, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(&positions, false);
 
     assert_eq!(best_position, 473);
     assert_eq!(best_fuel_consumption, 92676646);
+}
+
+#[test]
+fn test_day7_large_fuel_consumption()
+{
+    let (best_position, best_fuel_consumption) = get_position_with_lowest_fuel_consumption(&vec![0, 100000], false);
+
+    assert_eq!(best_position, 50000);
+    assert_eq!(best_fuel_consumption, 2500050000);
 }
\ No newline at end of file
@@ -2,21 +2,74 @@ use itertools::Itertools;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-mod util;
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util;
 
-fn main()
+pub struct Day8;
+
+impl Solution for Day8
 {
-    let input = util::read_input_file("day8.txt");
-    let lines = util::split_string_by_string(&input, "\n");
-    
-    let num_unique_digits = count_unique_digits(&lines);
-    println!("Part 1: Solution={}", num_unique_digits);
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error>
+    {
+        return util::collect_results(util::numbered_lines(input).iter().map(|(line_number, line)| {
+            validate_entry(line, *line_number)?;
+            return Ok(line.to_string());
+        }));
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, Error>
+    {
+        return Ok(count_unique_digits(lines).into());
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, Error>
+    {
+        return Ok(count_all_digits(lines).into());
+    }
+}
+
+// Every entry needs ten unique signal patterns, a '|' and four output digits made of the segments a-g
+pub fn validate_entry(line: &str, line_number: usize) -> Result<(), Error>
+{
+    let parts: Vec<&str> = line.split('|').collect();
+    if parts.len() != 2
+    {
+        return Err(Error::parse(line_number, 1, "expected exactly one '|' between the signal patterns and the output"));
+    }
+
+    if let Some(column) = line.find(|c: char| !('a'..='g').contains(&c) && c != ' ' && c != '|')
+    {
+        return Err(Error::parse(line_number, column + 1, "segments must be one of 'a' to 'g'"));
+    }
+
+    let patterns = util::split_with_columns(parts[0], " ");
+    if patterns.len() != 10
+    {
+        return Err(Error::parse(line_number, 1, format!("expected 10 signal patterns, found {}", patterns.len())));
+    }
+
+    for length in [2, 4]
+    {
+        if !patterns.iter().any(|(_, pattern)| pattern.len() == length)
+        {
+            return Err(Error::parse(line_number, 1, format!("missing a signal pattern with {} segments", length)));
+        }
+    }
+
+    let output_column = parts[0].len() + 2;
+    let outputs = util::split_with_columns(parts[1], " ");
+    if outputs.len() != 4
+    {
+        return Err(Error::parse(line_number, output_column, format!("expected 4 output digits, found {}", outputs.len())));
+    }
 
-    let num_all_digits = count_all_digits(&lines);
-    println!("Part 2: Solution={}", num_all_digits);
+    return Ok(());
 }
 
-fn count_unique_digits(lines: &Vec<String>) -> i32
+pub fn count_unique_digits(lines: &Vec<String>) -> i32
 {
     let mut sum_unique_digits = 0;
 
@@ -30,7 +83,7 @@ fn count_unique_digits(lines: &Vec<String>) -> i32
     return sum_unique_digits;
 }
 
-fn count_unique_digits_in_line(line: &String) -> i32
+pub fn count_unique_digits_in_line(line: &String) -> i32
 {
     let mut sum_of_unique_digits = 0;
     let parts = util::split_string_by_string(line, " ");
@@ -47,7 +100,7 @@ fn count_unique_digits_in_line(line: &String) -> i32
     return sum_of_unique_digits;
 }
 
-fn count_all_digits(lines: &Vec<String>) -> u64
+pub fn count_all_digits(lines: &Vec<String>) -> u64
 {
     let mut sum_of_digits: u64 = 0;
 
@@ -70,7 +123,7 @@ fn count_all_digits(lines: &Vec<String>) -> u64
     return sum_of_digits;
 }
 
-fn get_wire_mapping_for_line(line: &String) -> HashMap<String, i32>
+pub fn get_wire_mapping_for_line(line: &String) -> HashMap<String, i32>
 {
     let mut wire_mapping: HashMap<String, i32> = HashMap::new();
     let mut segments_one: HashSet<char> = HashSet::new();
@@ -110,8 +163,8 @@ fn get_wire_mapping_for_line(line: &String) -> HashMap<String, i32>
             2 => 1,
             3 => 7,
             4 => 4,
-            5 => get_number_from_ambigous_segments(&segments, &segments_one, &segments_four),
-            6 => get_number_from_ambigous_segments(&segments, &segments_one, &segments_four),
+            5 => get_number_from_ambigous_segments(segments, &segments_one, &segments_four),
+            6 => get_number_from_ambigous_segments(segments, &segments_one, &segments_four),
             7 => 8,
             _ => -1,
         };
@@ -127,7 +180,7 @@ fn get_wire_mapping_for_line(line: &String) -> HashMap<String, i32>
     return wire_mapping;
 }
 
-fn get_number_from_ambigous_segments(sorted_segments: &String, segments_one: &HashSet<char>, segments_four: &HashSet<char>) -> i32
+pub fn get_number_from_ambigous_segments(sorted_segments: &String, segments_one: &HashSet<char>, segments_four: &HashSet<char>) -> i32
 {
     let segments: HashSet<char> = sorted_segments.chars().collect();
 
@@ -174,7 +227,7 @@ fn get_number_from_ambigous_segments(sorted_segments: &String, segments_one: &Ha
     return -1;
 }
 
-fn get_digit_from_wire_mapping(line: &String, wire_mapping: &HashMap<String, i32>) -> i32
+pub fn get_digit_from_wire_mapping(line: &String, wire_mapping: &HashMap<String, i32>) -> i32
 {
     let sorted_segments: String = line.chars().sorted().collect();
     return wire_mapping[&sorted_segments];
@@ -183,17 +236,7 @@ fn get_digit_from_wire_mapping(line: &String, wire_mapping: &HashMap<String, i32
 #[test]
 fn test_day8_part1_example()
 {
-    let input = "
-be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce".to_string();
+    let input = util::read_input_file("examples/day8/example.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let num_unique_digits = count_unique_digits(&lines);
 
@@ -203,7 +246,7 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
 #[test]
 fn test_day8_part1_solution()
 {
-    let input = util::read_input_file("day8.txt");
+    let input = util::read_input_file("day8/input.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let num_unique_digits = count_unique_digits(&lines);
 
@@ -213,17 +256,7 @@ fn test_day8_part1_solution()
 #[test]
 fn test_day8_part2_example()
 {
-    let input = "
-be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce".to_string();
+    let input = util::read_input_file("examples/day8/example.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let num_unique_digits = count_all_digits(&lines);
 
@@ -233,7 +266,7 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
 #[test]
 fn test_day8_part2_solution()
 {
-    let input = util::read_input_file("day8.txt");
+    let input = util::read_input_file("day8/input.txt").unwrap();
     let lines = util::split_string_by_string(&input, "\n");
     let num_unique_digits = count_all_digits(&lines);
 
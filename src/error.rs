@@ -0,0 +1,81 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input file could not be read.
+    Io { path: String, source: io::Error },
+    /// The input file does not exist in any of the searched locations.
+    NotFound { name: String, searched: Vec<String> },
+    /// A problem at a specific position of the input. Line and column are 1-based.
+    Parse { file: Option<String>, line: usize, column: usize, message: String },
+    /// A problem with the input as a whole, e.g. a missing section.
+    Input { file: Option<String>, message: String },
+    /// Several problems of the same input, in the order they were found.
+    Multiple { errors: Vec<Error> },
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Error {
+        return Error::Parse { file: None, line, column, message: message.into() };
+    }
+
+    pub fn input(message: impl Into<String>) -> Error {
+        return Error::Input { file: None, message: message.into() };
+    }
+
+    /// Combines the problems of an input into one error, nested combinations are flattened.
+    pub fn combine(errors: Vec<Error>) -> Error {
+        let mut flattened: Vec<Error> = vec![];
+        for error in errors {
+            match error {
+                Error::Multiple { errors } => flattened.extend(errors),
+                error => flattened.push(error),
+            }
+        }
+
+        return if flattened.len() == 1 { flattened.pop().unwrap() } else { Error::Multiple { errors: flattened } };
+    }
+
+    /// The single problems of this error, which is only more than one for `Multiple`.
+    pub fn problems(&self) -> Vec<&Error> {
+        return match self {
+            Error::Multiple { errors } => errors.iter().collect(),
+            error => vec![error],
+        };
+    }
+
+    /// Attaches the name of the input file, so the error can be reported as `file:line:column`.
+    pub fn with_file(self, name: &str) -> Error {
+        return match self {
+            Error::Parse { line, column, message, .. } => Error::Parse { file: Some(name.to_string()), line, column, message },
+            Error::Input { message, .. } => Error::Input { file: Some(name.to_string()), message },
+            Error::Multiple { errors } => Error::Multiple { errors: errors.into_iter().map(|error| error.with_file(name)).collect() },
+            error => error,
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Error::Io { path, source } => write!(f, "could not read input file {}: {}", path, source),
+            Error::NotFound { name, searched } => write!(f, "input file {} not found, searched: {} (set {} or pass the input path explicitly)", name, searched.join(", "), crate::util::DATA_DIR_VARIABLE),
+            Error::Parse { file: Some(file), line, column, message } => write!(f, "{}:{}:{}: {}", file, line, column, message),
+            Error::Parse { file: None, line, column, message } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::Input { file: Some(file), message } => write!(f, "{}: {}", file, message),
+            Error::Input { file: None, message } => write!(f, "{}", message),
+            Error::Multiple { errors } => write!(f, "{}", errors.iter().map(|error| error.to_string()).collect::<Vec<String>>().join("\n")),
+        };
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        return match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        };
+    }
+}
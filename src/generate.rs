@@ -0,0 +1,762 @@
+use std::cmp;
+use std::collections::HashSet;
+
+use crate::day19;
+use crate::day23;
+use crate::day24;
+use crate::util::Random;
+
+/// Writes a random, well-formed input of the given size for one day, e.g. with this many lines or this width.
+pub type Generator = fn(&mut Random, usize) -> String;
+
+/// Returns the generator of the given day and the size of the real inputs, or None if the day has no generator.
+pub fn get_generator(day: u32) -> Option<(Generator, usize)> {
+    let generator: (Generator, usize) = match day {
+        1 => (generate_day1, 2000),
+        2 => (generate_day2, 1000),
+        3 => (generate_day3, 1000),
+        4 => (generate_day4, 100),
+        5 => (generate_day5, 500),
+        6 => (generate_day6, 300),
+        7 => (generate_day7, 1000),
+        8 => (generate_day8, 200),
+        9 => (generate_day9, 100),
+        10 => (generate_day10, 100),
+        11 => (generate_day11, 10),
+        12 => (generate_day12, 12),
+        13 => (generate_day13, 900),
+        14 => (generate_day14, 20),
+        15 => (generate_day15, 100),
+        16 => (generate_day16, 300),
+        17 => (generate_day17, 100),
+        18 => (generate_day18, 100),
+        19 => (generate_day19, 30),
+        20 => (generate_day20, 100),
+        21 => (generate_day21, 1),
+        22 => (generate_day22, 420),
+        23 => (generate_day23, 1),
+        24 => (generate_day24, 1),
+        25 => (generate_day25, 139),
+        _ => return None,
+    };
+
+    return Some(generator);
+}
+
+/// Generates an input for the given day with the seed, without a size the input is as large as the real ones.
+/// Returns None if the day has no generator.
+pub fn generate_input(day: u32, size: Option<usize>, seed: u64) -> Option<String> {
+    let (generator, default_size) = get_generator(day)?;
+    let mut random = Random::new(seed);
+
+    return Some(generator(&mut random, size.unwrap_or(default_size)));
+}
+
+/// A generated input on which a solution and its reference implementation disagree.
+#[derive(Clone, Debug)]
+pub struct Counterexample<T> {
+    pub size: usize,
+    pub seed: u64,
+    pub input: String,
+    pub expected: T,
+    pub actual: T,
+}
+
+/// Compares a solution with a slow but obviously correct reference implementation on generated inputs of the day,
+/// with the seeds `0..seeds` for every size. Smaller sizes are tried first, so the returned counterexample is small.
+pub fn find_counterexample<T, S, R>(day: u32, sizes: &[usize], seeds: u64, solve: S, reference: R) -> Option<Counterexample<T>>
+where
+    T: PartialEq,
+    S: Fn(&str) -> T,
+    R: Fn(&str) -> T,
+{
+    let mut sizes = sizes.to_vec();
+    sizes.sort();
+
+    for size in sizes {
+        for seed in 0..seeds {
+            let input = generate_input(day, Some(size), seed).expect("the day has no input generator");
+            let (expected, actual) = (reference(&input), solve(&input));
+
+            if expected != actual {
+                return Some(Counterexample { size, seed, input, expected, actual });
+            }
+        }
+    }
+
+    return None;
+}
+
+fn join_lines(lines: &[String]) -> String {
+    let mut input = lines.join("\n");
+    input.push('\n');
+    return input;
+}
+
+// Lines of `width` random characters out of `chars`
+fn random_rows(random: &mut Random, width: usize, height: usize, chars: &[char]) -> String {
+    let rows: Vec<String> = (0..height).map(|_| (0..width).map(|_| *random.choose(chars)).collect()).collect();
+    return join_lines(&rows);
+}
+
+const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+// A sea floor that slowly gets deeper, like the real sonar sweeps
+fn generate_day1(random: &mut Random, size: usize) -> String {
+    let mut depth = random.range(100, 200);
+    let mut lines: Vec<String> = vec![];
+
+    for _ in 0..size {
+        depth = cmp::max(0, depth + random.range(-10, 30));
+        lines.push(depth.to_string());
+    }
+
+    return join_lines(&lines);
+}
+
+// The submarine never goes up further than the surface
+fn generate_day2(random: &mut Random, size: usize) -> String {
+    let mut depth = 0;
+    let mut lines: Vec<String> = vec![];
+
+    for _ in 0..size {
+        let units = random.range(1, 9);
+        let direction = match random.below(3) {
+            0 => "forward",
+            1 if depth >= units => "up",
+            _ => "down",
+        };
+
+        depth += match direction {
+            "up" => -units,
+            "down" => units,
+            _ => 0,
+        };
+        lines.push(format!("{} {}", direction, units));
+    }
+
+    return join_lines(&lines);
+}
+
+// The bit criteria only end with a single number if all numbers are different, so there are at least twice as many
+// possible numbers as lines
+fn generate_day3(random: &mut Random, size: usize) -> String {
+    let width = cmp::max(12, (usize::BITS - (2 * size).leading_zeros()) as usize);
+    let mut numbers: HashSet<usize> = HashSet::new();
+    let mut lines: Vec<String> = vec![];
+
+    while lines.len() < size {
+        let number = random.below(1 << width);
+        if numbers.insert(number) {
+            lines.push(format!("{:0width$b}", number, width = width));
+        }
+    }
+
+    return join_lines(&lines);
+}
+
+// All numbers are drawn, so every board wins at some point
+fn generate_day4(random: &mut Random, size: usize) -> String {
+    let mut numbers: Vec<usize> = (0..100).collect();
+    random.shuffle(&mut numbers);
+    let mut lines: Vec<String> = vec![numbers.iter().map(|number| number.to_string()).collect::<Vec<String>>().join(",")];
+
+    for _ in 0..size {
+        random.shuffle(&mut numbers);
+        lines.push(String::new());
+
+        for row in numbers[..25].chunks(5) {
+            lines.push(row.iter().map(|number| format!("{:>2}", number)).collect::<Vec<String>>().join(" "));
+        }
+    }
+
+    return join_lines(&lines);
+}
+
+// Horizontal, vertical and diagonal lines on a 1000x1000 floor
+fn generate_day5(random: &mut Random, size: usize) -> String {
+    let mut lines: Vec<String> = vec![];
+
+    for _ in 0..size {
+        let (x1, y1) = (random.range(0, 999), random.range(0, 999));
+        let (x2, y2) = match random.below(3) {
+            0 => (x1, random.range(0, 999)),
+            1 => (random.range(0, 999), y1),
+            _ => {
+                let (dx, dy) = (*random.choose(&[-1, 1]), *random.choose(&[-1, 1]));
+                let x_space = if dx > 0 { 999 - x1 } else { x1 };
+                let y_space = if dy > 0 { 999 - y1 } else { y1 };
+                let length = random.range(0, cmp::min(x_space, y_space));
+                (x1 + dx * length, y1 + dy * length)
+            },
+        };
+
+        lines.push(format!("{},{} -> {},{}", x1, y1, x2, y2));
+    }
+
+    return join_lines(&lines);
+}
+
+fn generate_day6(random: &mut Random, size: usize) -> String {
+    let timers: Vec<String> = (0..size).map(|_| random.range(1, 5).to_string()).collect();
+    return join_lines(&[timers.join(",")]);
+}
+
+fn generate_day7(random: &mut Random, size: usize) -> String {
+    let positions: Vec<String> = (0..size).map(|_| random.range(0, 1999).to_string()).collect();
+    return join_lines(&[positions.join(",")]);
+}
+
+// Segments of the digits 0 to 9 on a correctly wired display
+const DIGIT_SEGMENTS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+fn scramble_digit(random: &mut Random, wiring: &[char], digit: usize) -> String {
+    let mut segments: Vec<char> = DIGIT_SEGMENTS[digit].chars().map(|c| wiring[c as usize - 'a' as usize]).collect();
+    random.shuffle(&mut segments);
+    return segments.into_iter().collect();
+}
+
+// Every entry has its own wiring of the segments
+fn generate_day8(random: &mut Random, size: usize) -> String {
+    let mut lines: Vec<String> = vec![];
+
+    for _ in 0..size {
+        let mut wiring: Vec<char> = ('a'..='g').collect();
+        random.shuffle(&mut wiring);
+
+        let mut digits: Vec<usize> = (0..10).collect();
+        random.shuffle(&mut digits);
+
+        let patterns: Vec<String> = digits.iter().map(|digit| scramble_digit(random, &wiring, *digit)).collect();
+        let outputs: Vec<String> = (0..4).map(|_| {
+            let digit = random.below(10);
+            return scramble_digit(random, &wiring, digit);
+        }).collect();
+
+        lines.push(format!("{} | {}", patterns.join(" "), outputs.join(" ")));
+    }
+
+    return join_lines(&lines);
+}
+
+// A square heightmap
+fn generate_day9(random: &mut Random, size: usize) -> String {
+    return random_rows(random, size, size, &DIGITS);
+}
+
+const CHUNK_CHARACTERS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// The completion score grows by a factor of 5 per missing character, so it must not get too long for a u64
+const MAX_OPEN_CHUNKS: usize = 12;
+
+// Every line is either corrupted or incomplete, like in the real navigation subsystem
+fn generate_day10(random: &mut Random, size: usize) -> String {
+    let mut lines: Vec<String> = vec![];
+
+    for _ in 0..size {
+        let length = random.range(90, 110) as usize;
+        let corrupted_at = if random.chance(0.5) { Some(random.below(length)) } else { None };
+        let mut is_corrupted = false;
+        let mut open_chunks: Vec<usize> = vec![];
+        let mut line = String::new();
+
+        for i in 0..length {
+            if !is_corrupted && corrupted_at.is_some_and(|position| position <= i) && !open_chunks.is_empty() {
+                // A closing character of any other chunk than the last open one
+                let chunk = (open_chunks[open_chunks.len() - 1] + random.range(1, 3) as usize) % CHUNK_CHARACTERS.len();
+                line.push(CHUNK_CHARACTERS[chunk].1);
+                is_corrupted = true;
+            } else if open_chunks.is_empty() || (open_chunks.len() < MAX_OPEN_CHUNKS && random.chance(0.55)) {
+                let chunk = random.below(CHUNK_CHARACTERS.len());
+                line.push(CHUNK_CHARACTERS[chunk].0);
+                open_chunks.push(chunk);
+            } else {
+                line.push(CHUNK_CHARACTERS[open_chunks.pop().unwrap()].1);
+            }
+        }
+
+        if !is_corrupted && open_chunks.is_empty() {
+            line.push(CHUNK_CHARACTERS[random.below(CHUNK_CHARACTERS.len())].0);
+        }
+
+        lines.push(line);
+    }
+
+    return join_lines(&lines);
+}
+
+// A square grid of octopuses, larger grids usually never flash all at once
+fn generate_day11(random: &mut Random, size: usize) -> String {
+    return random_rows(random, size, size, &DIGITS);
+}
+
+// Bijective base 26 with lowercase letters: a, b, ..., z, aa, ab, ...
+fn cave_name(index: usize) -> String {
+    let mut letters: Vec<char> = vec![];
+    let mut rest = index;
+
+    loop {
+        letters.push((b'a' + (rest % 26) as u8) as char);
+        if rest < 26 {
+            break;
+        }
+        rest = rest / 26 - 1;
+    }
+
+    return letters.iter().rev().collect();
+}
+
+// A connected cave system with `size` caves including start and end. Two big caves are never connected, otherwise
+// there would be infinitely many paths.
+fn generate_day12(random: &mut Random, size: usize) -> String {
+    let mut caves: Vec<String> = vec![String::from("start"), String::from("end")];
+    let mut next_name = 0;
+
+    while caves.len() < size {
+        let name = cave_name(next_name);
+        next_name += 1;
+        if name == "end" {
+            continue;
+        }
+
+        caves.push(if random.chance(0.25) { name.to_uppercase() } else { name });
+    }
+
+    let is_big = |cave: usize| caves[cave].chars().all(|c| c.is_ascii_uppercase());
+    let mut connected: HashSet<(usize, usize)> = HashSet::new();
+    let mut connections: Vec<(usize, usize)> = vec![];
+
+    // Every cave is connected to one of the caves before, start is small so there always is a candidate
+    for cave in 1..caves.len() {
+        let other = loop {
+            let other = random.below(cave);
+            if !is_big(cave) || !is_big(other) {
+                break other;
+            }
+        };
+
+        connected.insert((other, cave));
+        connections.push((cave, other));
+    }
+
+    for _ in 0..caves.len() / 2 {
+        let (cave, other) = (random.below(caves.len()), random.below(caves.len()));
+        if cave != other && !(is_big(cave) && is_big(other)) && connected.insert((cmp::min(cave, other), cmp::max(cave, other))) {
+            connections.push((cave, other));
+        }
+    }
+
+    random.shuffle(&mut connections);
+    let lines: Vec<String> = connections.iter().map(|(cave, other)| format!("{}-{}", caves[*cave], caves[*other])).collect();
+    return join_lines(&lines);
+}
+
+// The paper is folded alternately along x and y until 40x6 are left, like the real codes. It has at least 1000 times
+// as many positions as dots. The dots are placed on the folded paper and randomly unfolded, so none of them lies on a
+// fold line.
+fn generate_day13(random: &mut Random, size: usize) -> String {
+    let (mut width, mut height) = (40, 6);
+    let mut folds: Vec<(usize, usize)> = vec![];
+
+    loop {
+        folds.push((width, height));
+        width = 2 * width + 1;
+        height = 2 * height + 1;
+
+        if width * height >= size * 1000 {
+            break;
+        }
+    }
+
+    let mut dots: HashSet<(usize, usize)> = HashSet::new();
+    let mut lines: Vec<String> = vec![];
+
+    while lines.len() < size {
+        let (mut x, mut y) = (random.below(40), random.below(6));
+        for (fold_x, fold_y) in &folds {
+            if random.chance(0.5) {
+                x = 2 * fold_x - x;
+            }
+            if random.chance(0.5) {
+                y = 2 * fold_y - y;
+            }
+        }
+
+        if dots.insert((x, y)) {
+            lines.push(format!("{},{}", x, y));
+        }
+    }
+
+    lines.push(String::new());
+    for (fold_x, fold_y) in folds.iter().rev() {
+        lines.push(format!("fold along x={}", fold_x));
+        lines.push(format!("fold along y={}", fold_y));
+    }
+
+    return join_lines(&lines);
+}
+
+// A template of `size` elements out of 10 letters and an insertion rule for every pair of them
+fn generate_day14(random: &mut Random, size: usize) -> String {
+    let mut letters: Vec<char> = ('A'..='Z').collect();
+    random.shuffle(&mut letters);
+    letters.truncate(10);
+
+    let mut lines: Vec<String> = vec![(0..size).map(|_| *random.choose(&letters)).collect(), String::new()];
+    for first in &letters {
+        for second in &letters {
+            lines.push(format!("{}{} -> {}", first, second, random.choose(&letters)));
+        }
+    }
+
+    return join_lines(&lines);
+}
+
+// A square cave
+fn generate_day15(random: &mut Random, size: usize) -> String {
+    return random_rows(random, size, size, &DIGITS[1..]);
+}
+
+// Sums and products that would get larger than this are replaced with minimums, so evaluating never overflows
+const MAX_PACKET_VALUE: u64 = 1 << 40;
+
+// Appends the lowest `length` bits of the value, the most significant first
+fn push_bits(bits: &mut Vec<bool>, value: u64, length: usize) {
+    for i in (0..length).rev() {
+        bits.push((value >> i) & 1 == 1);
+    }
+}
+
+// Appends a random packet that consists of `packets` packets in total and returns its value
+fn generate_packet(random: &mut Random, packets: usize, bits: &mut Vec<bool>) -> u64 {
+    let version = random.below(8) as u64;
+
+    if packets == 1 {
+        let groups = random.range(1, 3) as usize;
+        let value = random.below(1 << (4 * groups)) as u64;
+
+        push_bits(bits, version, 3);
+        push_bits(bits, 4, 3);
+        for group in (0..groups).rev() {
+            bits.push(group > 0);
+            push_bits(bits, value >> (4 * group), 4);
+        }
+        return value;
+    }
+
+    // Comparisons need exactly two sub packets, at most 4 sub packets make the trees deep rather than wide
+    let mut type_id = if packets >= 3 { *random.choose(&[0, 1, 2, 3, 5, 6, 7]) } else { random.range(0, 3) as u64 };
+    let count = if type_id >= 5 { 2 } else { random.range(1, cmp::min(packets - 1, 4) as i64) as usize };
+
+    // Splits the remaining packets into `count` non-empty sub packets
+    let mut cuts: Vec<usize> = (0..count - 1).map(|_| random.below(packets - count)).collect();
+    cuts.push(0);
+    cuts.push(packets - 1 - count);
+    cuts.sort();
+
+    let mut sub_packet_bits: Vec<bool> = vec![];
+    let mut values: Vec<u64> = vec![];
+    for i in 0..count {
+        values.push(generate_packet(random, cuts[i + 1] - cuts[i] + 1, &mut sub_packet_bits));
+    }
+
+    let value = match type_id {
+        0 => values.iter().try_fold(0u64, |sum, value| sum.checked_add(*value)),
+        1 => values.iter().try_fold(1u64, |product, value| product.checked_mul(*value)),
+        2 => values.iter().min().copied(),
+        3 => values.iter().max().copied(),
+        5 => Some((values[0] > values[1]) as u64),
+        6 => Some((values[0] < values[1]) as u64),
+        _ => Some((values[0] == values[1]) as u64),
+    };
+    let value = match value {
+        Some(value) if value <= MAX_PACKET_VALUE => value,
+        _ => {
+            type_id = 2;
+            *values.iter().min().unwrap()
+        },
+    };
+
+    push_bits(bits, version, 3);
+    push_bits(bits, type_id, 3);
+    if sub_packet_bits.len() < (1 << 15) && random.chance(0.5) {
+        bits.push(false);
+        push_bits(bits, sub_packet_bits.len() as u64, 15);
+    } else {
+        bits.push(true);
+        push_bits(bits, count as u64, 11);
+    }
+    bits.extend(sub_packet_bits);
+
+    return value;
+}
+
+// A transmission of `size` packets in total
+fn generate_day16(random: &mut Random, size: usize) -> String {
+    let mut bits: Vec<bool> = vec![];
+    generate_packet(random, cmp::max(size, 1), &mut bits);
+
+    // The transmission is padded with zeros to whole bytes
+    while !bits.len().is_multiple_of(8) {
+        bits.push(false);
+    }
+
+    let transmission: String = bits.chunks(4).map(|nibble| {
+        let digit = nibble.iter().fold(0, |digit, bit| digit * 2 + *bit as u32);
+        return char::from_digit(digit, 16).unwrap().to_ascii_uppercase();
+    }).collect();
+    return join_lines(&[transmission]);
+}
+
+// A target area about `size` steps away in front of and below the submarine. The solution only tries velocities
+// up to 1000, so the area stays within that distance.
+fn generate_day17(random: &mut Random, size: usize) -> String {
+    let distance = size.clamp(10, 750) as i64;
+    let x_min = random.range(distance / 2, distance);
+    let x_max = x_min + random.range(0, distance / 4);
+    let y_min = -random.range(distance / 2, distance);
+    let y_max = y_min + random.range(0, distance / 4);
+
+    return join_lines(&[format!("target area: x={}..{}, y={}..{}", x_min, x_max, y_min, y_max)]);
+}
+
+// Numbers nested inside more than 4 pairs would already have been exploded
+fn generate_snailfish_element(random: &mut Random, depth: usize, number: &mut String) {
+    if depth == 4 || (depth > 0 && random.chance(0.4)) {
+        number.push(*random.choose(&DIGITS));
+        return;
+    }
+
+    number.push('[');
+    generate_snailfish_element(random, depth + 1, number);
+    number.push(',');
+    generate_snailfish_element(random, depth + 1, number);
+    number.push(']');
+}
+
+fn generate_day18(random: &mut Random, size: usize) -> String {
+    let lines: Vec<String> = (0..cmp::max(size, 2)).map(|_| {
+        let mut number = String::new();
+        generate_snailfish_element(random, 0, &mut number);
+        return number;
+    }).collect();
+
+    return join_lines(&lines);
+}
+
+// A random position at most `distance` away from the center on every axis
+fn random_position(random: &mut Random, center: [i32; 3], distance: i32) -> [i32; 3] {
+    return center.map(|coordinate| coordinate + random.range(-distance as i64, distance as i64) as i32);
+}
+
+fn generate_day19(random: &mut Random, size: usize) -> String {
+    return generate_scanner_reports(random, size).0;
+}
+
+/// The scanner reports of day 19 together with the number of beacons and the scanner positions they were made from, so
+/// their answers are known without solving them. The scanners form a chain in which neighbours see at least 12 common
+/// beacons. Every scanner reports all beacons within 1000 on every axis, relative to itself and in its own orientation.
+pub fn generate_scanner_reports(random: &mut Random, size: usize) -> (String, usize, Vec<[i32; 3]>) {
+    let rotations = day19::get_rotations();
+    let mut scanners: Vec<[i32; 3]> = vec![[0, 0, 0]];
+    let mut beacons: Vec<[i32; 3]> = vec![];
+    let mut known_beacons: HashSet<[i32; 3]> = HashSet::new();
+
+    for i in 0..cmp::max(size, 1) {
+        let mut new_beacons: Vec<[i32; 3]> = vec![];
+
+        if i > 0 {
+            let previous = scanners[i - 1];
+            let scanner = random_position(random, previous, 1200);
+            scanners.push(scanner);
+
+            // The overlap of two cubes with an edge of 2000 whose centers are at most 1200 apart
+            let overlap_min = [0, 1, 2].map(|axis| cmp::max(previous[axis], scanner[axis]) - 1000);
+            let overlap_max = [0, 1, 2].map(|axis| cmp::min(previous[axis], scanner[axis]) + 1000);
+            while new_beacons.len() < 12 {
+                let beacon = [0, 1, 2].map(|axis| random.range(overlap_min[axis] as i64, overlap_max[axis] as i64) as i32);
+                if known_beacons.insert(beacon) {
+                    new_beacons.push(beacon);
+                }
+            }
+        }
+
+        let extra_beacons = new_beacons.len() + random.range(8, 14) as usize;
+        while new_beacons.len() < extra_beacons {
+            let beacon = random_position(random, scanners[i], 1000);
+            if known_beacons.insert(beacon) {
+                new_beacons.push(beacon);
+            }
+        }
+
+        beacons.extend(new_beacons);
+    }
+
+    let mut lines: Vec<String> = vec![];
+    for (id, scanner) in scanners.iter().enumerate() {
+        let rotation = random.choose(&rotations);
+        let mut reports: Vec<[i32; 3]> = beacons.iter()
+            .map(|beacon| [0, 1, 2].map(|axis| beacon[axis] - scanner[axis]))
+            .filter(|position| position.iter().all(|coordinate| coordinate.abs() <= 1000))
+            .map(|position| rotation.apply(&position))
+            .collect();
+        random.shuffle(&mut reports);
+
+        if id > 0 {
+            lines.push(String::new());
+        }
+        lines.push(format!("--- scanner {} ---", id));
+        lines.extend(reports.iter().map(|position| format!("{},{},{}", position[0], position[1], position[2])));
+    }
+
+    return (join_lines(&lines), beacons.len(), scanners);
+}
+
+// If the algorithm turns on all dark pixels it must turn off all lit ones, otherwise infinitely many pixels are lit
+fn generate_day20(random: &mut Random, size: usize) -> String {
+    let mut algorithm: Vec<char> = (0..512).map(|_| *random.choose(&['#', '.'])).collect();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+
+    let algorithm: String = algorithm.into_iter().collect();
+    return format!("{}\n\n{}", algorithm, random_rows(random, size, size, &['#', '.']));
+}
+
+// The game has no size, it is ignored
+fn generate_day21(random: &mut Random, _size: usize) -> String {
+    let lines: Vec<String> = (1..=2).map(|player| format!("Player {} starting position: {}", player, random.range(1, 10))).collect();
+    return join_lines(&lines);
+}
+
+// The first 20 steps are inside the initialization region like in the real inputs, the others are large
+fn generate_day22(random: &mut Random, size: usize) -> String {
+    let mut lines: Vec<String> = vec![];
+
+    for i in 0..size {
+        let state = if i == 0 || random.chance(0.6) { "on" } else { "off" };
+        let (extent, max_length) = if i < 20 { (50, 50) } else { (100000, 40000) };
+
+        let ranges: Vec<String> = ["x", "y", "z"].iter().map(|axis| {
+            let min = random.range(-extent, extent);
+            let max = cmp::min(extent, min + random.range(0, max_length));
+            return format!("{}={}..{}", axis, min, max);
+        }).collect();
+
+        lines.push(format!("{} {}", state, ranges.join(",")));
+    }
+
+    return join_lines(&lines);
+}
+
+// The burrow has a fixed size, the size is ignored. All amphipods start in the rooms, some of the shuffles can only
+// be organized before unfolding and are drawn again.
+fn generate_day23(random: &mut Random, _size: usize) -> String {
+    let mut amphipods: Vec<char> = "AABBCCDD".chars().collect();
+
+    loop {
+        random.shuffle(&mut amphipods);
+
+        let lines = [
+            String::from("#############"),
+            String::from("#...........#"),
+            format!("###{}#{}#{}#{}###", amphipods[0], amphipods[1], amphipods[2], amphipods[3]),
+            format!("  #{}#{}#{}#{}#", amphipods[4], amphipods[5], amphipods[6], amphipods[7]),
+            String::from("  #########"),
+        ];
+        let input = join_lines(&lines);
+
+        let burrow = day23::parse_burrow(&input).unwrap();
+        if day23::organize(&day23::unfold(&burrow)).is_some() {
+            return input;
+        }
+    }
+}
+
+// The program has a fixed size, the size is ignored. Half of the digit blocks push an offset, the other half pop one
+// and check the digit against it. Every popped digit differs by at most 8 from its pushed one, so there are always
+// valid model numbers.
+fn generate_day24(random: &mut Random, _size: usize) -> String {
+    let mut pushed_offsets: Vec<i64> = vec![];
+    let mut lines: Vec<String> = vec![];
+
+    for digit in 0..day24::MODEL_NUMBER_LENGTH {
+        let remaining = day24::MODEL_NUMBER_LENGTH - digit;
+        let push = pushed_offsets.is_empty() || (pushed_offsets.len() < remaining && random.chance(0.5));
+
+        let offset = random.range(1, 16);
+        let (divisor, check) = if push {
+            pushed_offsets.push(offset);
+            (1, random.range(10, 16))
+        } else {
+            (26, random.range(-8, 8) - pushed_offsets.pop().unwrap())
+        };
+
+        for (i, instruction) in day24::BLOCK_TEMPLATE.iter().enumerate() {
+            let parameter = match i {
+                day24::DIVISOR_INDEX => divisor,
+                day24::CHECK_INDEX => check,
+                day24::OFFSET_INDEX => offset,
+                _ => 0,
+            };
+            lines.push(instruction.replace('?', &parameter.to_string()));
+        }
+    }
+
+    return join_lines(&lines);
+}
+
+// A square sea floor, about as crowded as the real one
+fn generate_day25(random: &mut Random, size: usize) -> String {
+    return random_rows(random, size, size, &['>', '>', '>', 'v', 'v', 'v', '.', '.', '.', '.', '.']);
+}
+
+#[test]
+fn test_generate_inputs_parse() {
+    for day in 1..=crate::NUMBER_OF_DAYS {
+        let runner = crate::get_runner(day).unwrap();
+
+        // Days that were just added with aoc new have no generator yet. Generated burrows must also be solvable.
+        for seed in 0..3 {
+            let Some(input) = generate_input(day, Some(20), seed) else { continue };
+            let parts: &[u32] = if day == 23 { &[1, 2] } else { &[] };
+            if let Err(error) = runner(&input, parts, false) {
+                panic!("the generated input of day {} with seed {} fails: {}\n{}", day, seed, error, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_find_counterexample() {
+    let count_lines = |input: &str| input.lines().count();
+    assert!(find_counterexample(1, &[1, 10], 5, count_lines, count_lines).is_none());
+
+    // A broken solution that stops counting after 3 lines is first caught with the smallest size above 3
+    let counterexample = find_counterexample(1, &[10, 2, 4, 3], 5, |input| cmp::min(count_lines(input), 3), count_lines).unwrap();
+    assert_eq!((counterexample.size, counterexample.seed, counterexample.expected, counterexample.actual), (4, 0, 4, 3));
+}
+
+#[test]
+fn test_generate_inputs_are_seeded() {
+    assert_eq!(generate_input(16, Some(50), 1), generate_input(16, Some(50), 1));
+    assert_ne!(generate_input(16, Some(50), 1), generate_input(16, Some(50), 2));
+    assert_eq!(generate_input(5, Some(100000), 1).unwrap().lines().count(), 100000);
+    assert_eq!(generate_input(26, None, 1), None);
+}
+
+#[test]
+fn test_generate_scanner_reports_known_answers() {
+    let largest_distance = |scanners: &Vec<[i32; 3]>| scanners.iter().flat_map(|a| scanners.iter().map(move |b| (0..3).map(|axis| (a[axis] - b[axis]).abs()).sum::<i32>())).max().unwrap();
+
+    for seed in 0..3 {
+        let (input, beacons, scanners) = generate_scanner_reports(&mut Random::new(seed), 6);
+        assert_eq!(crate::solve(19, 1, &input).unwrap().unwrap(), beacons.into());
+        assert_eq!(crate::solve(19, 2, &input).unwrap().unwrap(), largest_distance(&scanners).into());
+    }
+
+    // The day 19 input in data is this report, its answers come from the generator and not from the solver
+    let (input, beacons, scanners) = generate_scanner_reports(&mut Random::new(19), 30);
+    assert_eq!(crate::util::read_input_file("day19/input.txt").unwrap(), input);
+    assert_eq!((beacons, largest_distance(&scanners)), (684, 17699));
+}
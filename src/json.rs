@@ -0,0 +1,63 @@
+use crate::solution::Answer;
+use crate::DayResult;
+
+/// Formats the results as a JSON array with one object per solved part, e.g.
+/// `{"day": 1, "part": 2, "type": "number", "answer": 1571, "elapsed_ns": 1203}`.
+/// Text answers (like the folded paper of day13) are JSON strings with `\n` line breaks.
+pub fn format_results(results: &[(u32, DayResult)]) -> String {
+    let mut objects: Vec<String> = vec![];
+
+    for (day, result) in results {
+        for part_result in &result.parts {
+            let (answer_type, answer) = match &part_result.answer {
+                Answer::Number(number) => ("number", number.to_string()),
+                Answer::Text(text) => ("text", escape_string(text)),
+            };
+
+            objects.push(format!("  {{\"day\": {}, \"part\": {}, \"type\": \"{}\", \"answer\": {}, \"elapsed_ns\": {}}}", day, part_result.part, answer_type, answer, part_result.elapsed.as_nanos()));
+        }
+    }
+
+    if objects.is_empty() {
+        return String::from("[]");
+    }
+
+    return format!("[\n{}\n]", objects.join(",\n"));
+}
+
+/// Returns the text as a quoted JSON string.
+pub fn escape_string(text: &str) -> String {
+    let mut escaped = String::from("\"");
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            c if (c as u32) < 0x20 => escaped += &format!("\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    return escaped;
+}
+
+#[test]
+fn test_format_results() {
+    use crate::PartResult;
+    use std::time::Duration;
+
+    let parts = vec![
+        PartResult { part: 1, answer: Answer::Number(687), elapsed: Duration::from_nanos(12) },
+        PartResult { part: 2, answer: Answer::Text(String::from("#.\"\n")), elapsed: Duration::from_nanos(34) },
+    ];
+    let json = format_results(&[(13, DayResult { parse_elapsed: Duration::from_nanos(1), parts, values: vec![] })]);
+
+    assert_eq!(json, "[
+  {\"day\": 13, \"part\": 1, \"type\": \"number\", \"answer\": 687, \"elapsed_ns\": 12},
+  {\"day\": 13, \"part\": 2, \"type\": \"text\", \"answer\": \"#.\\\"\\n\", \"elapsed_ns\": 34}
+]");
+}
@@ -0,0 +1,200 @@
+use std::env;
+use std::process;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod generate;
+pub mod json;
+pub mod scaffold;
+pub mod solution;
+pub mod util;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+// aoc new: modules
+
+pub use error::Error;
+pub use solution::{Answer, Solution};
+
+/// The answer of one part and the time it took to solve it.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub part: u32,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// The requested parts of one day, all solved with the same parsed input, and the named values of the day if they were
+/// requested.
+#[derive(Clone, Debug)]
+pub struct DayResult {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+    pub values: Vec<(String, Answer)>,
+}
+
+pub type Runner = fn(&str, &[u32], bool) -> Result<DayResult, Error>;
+
+pub type Checker = fn(&str) -> Result<(), Error>;
+
+pub const NUMBER_OF_DAYS: u32 = 25;
+
+/// Parses the input once and solves the requested parts (1 and/or 2) with it, timing every step. The named values
+/// are only computed with `with_values`, they are not timed.
+pub fn run<S: Solution>(input: &str, parts: &[u32], with_values: bool) -> Result<DayResult, Error> {
+    let start = Instant::now();
+    let parsed_input = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let mut part_results: Vec<PartResult> = vec![];
+    for part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&parsed_input)?,
+            2 => S::part2(&parsed_input)?,
+            _ => panic!("illegal part {}", part),
+        };
+        part_results.push(PartResult { part: *part, answer, elapsed: start.elapsed() });
+    }
+
+    let values = if with_values { S::values(&parsed_input) } else { vec![] };
+    return Ok(DayResult { parse_elapsed, parts: part_results, values });
+}
+
+/// Entry point of the single day binaries: reads and parses the input and prints both parts.
+/// The input is read from the path given as first argument ("-" for stdin) or looked up by `input_file`.
+pub fn run_binary<S: Solution>(input_file: &str) {
+    let result = match env::args().nth(1) {
+        Some(path) => util::read_input(&path).and_then(|input| run::<S>(&input, &[1, 2], false).map_err(|error| error.with_file(&path))),
+        None => util::read_input_file(input_file).and_then(|input| run::<S>(&input, &[1, 2], false).map_err(|error| error.with_file(input_file))),
+    };
+
+    match result {
+        Ok(result) => {
+            for part_result in &result.parts {
+                println!("{}", format_answer(part_result.part, &part_result.answer));
+            }
+        },
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        },
+    }
+}
+
+/// Formats an answer the way all binaries print it. Multi-line answers start on a new line.
+pub fn format_answer(part: u32, answer: &Answer) -> String {
+    return match answer {
+        Answer::Text(text) if text.contains('\n') => format!("Part {}:\n{}", part, text),
+        _ => format!("Part {}: Solution={}", part, answer),
+    };
+}
+
+/// Returns the runner for the given day, or None if the day is not solved yet.
+pub fn get_runner(day: u32) -> Option<Runner> {
+    let runner: Runner = match day {
+        1 => run::<day1::Day1>,
+        2 => run::<day2::Day2>,
+        3 => run::<day3::Day3>,
+        4 => run::<day4::Day4>,
+        5 => run::<day5::Day5>,
+        6 => run::<day6::Day6>,
+        7 => run::<day7::Day7>,
+        8 => run::<day8::Day8>,
+        9 => run::<day9::Day9>,
+        10 => run::<day10::Day10>,
+        11 => run::<day11::Day11>,
+        12 => run::<day12::Day12>,
+        13 => run::<day13::Day13>,
+        14 => run::<day14::Day14>,
+        15 => run::<day15::Day15>,
+        16 => run::<day16::Day16>,
+        17 => run::<day17::Day17>,
+        18 => run::<day18::Day18>,
+        19 => run::<day19::Day19>,
+        20 => run::<day20::Day20>,
+        21 => run::<day21::Day21>,
+        22 => run::<day22::Day22>,
+        23 => run::<day23::Day23>,
+        24 => run::<day24::Day24>,
+        25 => run::<day25::Day25>,
+        // aoc new: runners
+        _ => return None,
+    };
+
+    return Some(runner);
+}
+
+/// Returns the check (see `Solution::check`) of the given day, or None if the day is not solved yet.
+pub fn get_checker(day: u32) -> Option<Checker> {
+    let checker: Checker = match day {
+        1 => day1::Day1::check,
+        2 => day2::Day2::check,
+        3 => day3::Day3::check,
+        4 => day4::Day4::check,
+        5 => day5::Day5::check,
+        6 => day6::Day6::check,
+        7 => day7::Day7::check,
+        8 => day8::Day8::check,
+        9 => day9::Day9::check,
+        10 => day10::Day10::check,
+        11 => day11::Day11::check,
+        12 => day12::Day12::check,
+        13 => day13::Day13::check,
+        14 => day14::Day14::check,
+        15 => day15::Day15::check,
+        16 => day16::Day16::check,
+        17 => day17::Day17::check,
+        18 => day18::Day18::check,
+        19 => day19::Day19::check,
+        20 => day20::Day20::check,
+        21 => day21::Day21::check,
+        22 => day22::Day22::check,
+        23 => day23::Day23::check,
+        24 => day24::Day24::check,
+        25 => day25::Day25::check,
+        // aoc new: checkers
+        _ => return None,
+    };
+
+    return Some(checker);
+}
+
+/// Solves a single part (1 or 2) of the given day, or returns None if the day is not solved yet.
+pub fn solve(day: u32, part: u32, input: &str) -> Option<Result<Answer, Error>> {
+    let runner = get_runner(day)?;
+
+    return Some(runner(input, &[part], false).map(|mut result| result.parts.remove(0).answer));
+}
+
+/// Returns the named values (see `Solution::values`) of the given day, or None if the day is not solved yet.
+pub fn solve_values(day: u32, input: &str) -> Option<Result<Vec<(String, Answer)>, Error>> {
+    let runner = get_runner(day)?;
+
+    return Some(runner(input, &[], true).map(|result| result.values));
+}
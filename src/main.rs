@@ -0,0 +1,675 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::io::BufReader;
+use std::path::Path;
+use std::process;
+
+use aoc2021::bench::{self, Measurement};
+use aoc2021::day1::{stats, stream, Day1};
+use aoc2021::day2::{self, trajectory, Day2};
+use aoc2021::solution::Solution;
+use aoc2021::{generate, json, scaffold, util, DayResult, Error};
+
+const USAGE: &str = "Usage: aoc run [DAYS] [--part 1|2] [--input PATH] [--format text|json]
+       aoc bench [DAYS] [--runs N] [--save PATH] [--baseline PATH] [--threshold PERCENT]
+       aoc new DAY
+       aoc generate DAY [--size N] [--seed N] [--output PATH]
+       aoc check DAY PATH
+       aoc sonar [--windows SIZES] [--every N] [--input PATH]
+       aoc stats [--input PATH] [--window N] [--spike-window N] [--threshold DEPTH] [--format text|csv]
+       aoc course [--input PATH] [--model direct|aim] [--below DEPTH] [--format text|csv|json]
+
+DAYS can be a single day (14), an inclusive range (1-18), a comma separated list (1,3,7) or 'all'.
+Without DAYS, all days are run. --input is only allowed when a single day is selected, '-' reads the input from stdin.
+Otherwise the input dayN/input.txt is looked up in $AOC_DATA_DIR, ./data and the data directory of the crate, in this order.
+--format json prints a JSON array with day, part, type, answer and elapsed_ns of every solved part.
+
+bench solves every day --runs times (default 10) and reports min, median and max of parsing and both parts.
+--save writes the results to a file, --baseline compares the medians with a saved file and fails if one of them
+is more than --threshold percent (default 10) slower.
+
+new creates src/dayN.rs with ignored tests, the binary src/bin/dayN.rs, an empty input and answers file in data/dayN
+and in data/examples/dayN, and registers the day in src/lib.rs and Cargo.toml. It has to be run in the crate directory.
+
+generate writes a random input for a day to stdout or --output. --size sets e.g. the number of lines or the width of
+a grid (default: about the size of the real inputs), the same --seed (default 0) always gives the same input.
+
+check reports all structural problems of an input file ('-' for stdin) with their line numbers, without solving it.
+
+sonar reads day 1 depth measurements line by line from --input (default: stdin) as they arrive and counts the
+increases of the sums of --windows consecutive measurements (default 1,3). It prints a summary every --every
+measurements (default 1000, 0 only prints the final one) and keeps only the last measurements in memory.
+
+stats reports min, max, mean, the moving averages of --window measurements (default 3), the longest increasing run,
+the largest drop and the spikes of the day 1 depths. A spike differs by more than --threshold (default 50) from the
+mean of the --spike-window (default 10) measurements before it. --format csv prints one row per measurement.
+
+course follows the day 2 commands with the --model of part 1 (direct) or part 2 (aim, the default) and reports the
+final position, the maximum depth and the first step deeper than --below. --format csv and json export the position
+and aim after every command.";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run(RunOptions),
+    Bench(BenchOptions),
+    New(u32),
+    Generate(GenerateOptions),
+    Check(u32, String),
+    Sonar(SonarOptions),
+    Stats(StatsCommandOptions),
+    Course(CourseOptions),
+}
+
+#[derive(Debug, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq)]
+struct RunOptions {
+    days: Vec<u32>,
+    parts: Vec<u32>,
+    input: Option<String>,
+    format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+struct BenchOptions {
+    days: Vec<u32>,
+    runs: usize,
+    save: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct GenerateOptions {
+    day: u32,
+    size: Option<usize>,
+    seed: u64,
+    output: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct SonarOptions {
+    window_sizes: Vec<usize>,
+    every: u64,
+    input: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct StatsCommandOptions {
+    input: Option<String>,
+    stats: stats::StatsOptions,
+    format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+struct CourseOptions {
+    input: Option<String>,
+    /// The name of a model, see `day2::get_model`.
+    model: String,
+    below: Option<i32>,
+    format: Format,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_arguments(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(options) => run(&options),
+        Command::Bench(options) => run_bench(&options),
+        Command::New(day) => run_new(day),
+        Command::Generate(options) => run_generate(&options),
+        Command::Check(day, path) => run_check(day, &path),
+        Command::Sonar(options) => run_sonar(&options),
+        Command::Stats(options) => run_stats(&options),
+        Command::Course(options) => run_course(&options),
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+fn run(options: &RunOptions) -> Result<(), Error> {
+    if options.format == Format::Json {
+        let results = options.days.iter().map(|day| Ok((*day, run_day(*day, options)?))).collect::<Result<Vec<_>, Error>>()?;
+        println!("{}", json::format_results(&results));
+        return Ok(());
+    }
+
+    for day in &options.days {
+        let result = run_day(*day, options)?;
+
+        for part_result in &result.parts {
+            let solution = part_result.answer.to_string();
+            if solution.contains('\n') {
+                println!("Day {} Part {}:\n{}", day, part_result.part, solution);
+            } else {
+                println!("Day {} Part {}: Solution={}", day, part_result.part, solution);
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+fn run_day(day: u32, options: &RunOptions) -> Result<DayResult, Error> {
+    let runner = aoc2021::get_runner(day).ok_or_else(|| Error::input(format!("day {} is not solved", day)))?;
+    let (input_name, input) = read_day_input(day, &options.input)?;
+
+    return runner(&input, &options.parts, false).map_err(|error| error.with_file(&input_name));
+}
+
+// Returns the name and the contents of the input, either from the given path or the default input of the day
+fn read_day_input(day: u32, path: &Option<String>) -> Result<(String, String), Error> {
+    return match path {
+        Some(path) => Ok((path.to_string(), util::read_input(path)?)),
+        None => {
+            let input_file = util::input_file_name(day, util::DEFAULT_INPUT_NAME);
+            let input = util::read_input_file(&input_file)?;
+            Ok((input_file, input))
+        },
+    };
+}
+
+fn run_bench(options: &BenchOptions) -> Result<(), Error> {
+    let baseline = match &options.baseline {
+        Some(path) => bench::parse_baseline(&util::read_input(path)?).map_err(|error| error.with_file(path))?,
+        None => vec![],
+    };
+
+    println!("{:<5}{:<7}{:>12}{:>12}{:>12}{:>12}", "Day", "Step", "Min", "Median", "Max", "Baseline");
+
+    let mut measurements: Vec<Measurement> = vec![];
+    let mut regressions = 0;
+    for day in &options.days {
+        let (input_name, input) = read_day_input(*day, &None)?;
+
+        for measurement in bench::benchmark(*day, &input, options.runs).map_err(|error| error.with_file(&input_name))? {
+            let statistics = &measurement.statistics;
+            let change = match bench::compare_to_baseline(&measurement, &baseline) {
+                Some(change) if change > options.threshold => {
+                    regressions += 1;
+                    format!("{:+.1}% !", change)
+                },
+                Some(change) => format!("{:+.1}%", change),
+                None => String::from("-"),
+            };
+
+            println!("{:<5}{:<7}{:>12}{:>12}{:>12}{:>12}", day, measurement.step, bench::format_duration(statistics.min), bench::format_duration(statistics.median), bench::format_duration(statistics.max), change);
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        fs::write(path, bench::format_baseline(&measurements)).map_err(|source| Error::Io { path: path.to_string(), source })?;
+    }
+
+    if regressions > 0 {
+        return Err(Error::input(format!("{} steps are more than {}% slower than the baseline", regressions, options.threshold)));
+    }
+
+    return Ok(());
+}
+
+fn run_new(day: u32) -> Result<(), Error> {
+    for path in scaffold::create_day(Path::new("."), day)? {
+        println!("{}", path.display());
+    }
+
+    return Ok(());
+}
+
+fn run_generate(options: &GenerateOptions) -> Result<(), Error> {
+    let input = generate::generate_input(options.day, options.size, options.seed).ok_or_else(|| Error::input(format!("day {} has no input generator", options.day)))?;
+
+    return match &options.output {
+        Some(path) => fs::write(path, input).map_err(|source| Error::Io { path: path.to_string(), source }),
+        None => {
+            print!("{}", input);
+            Ok(())
+        },
+    };
+}
+
+fn run_check(day: u32, path: &str) -> Result<(), Error> {
+    let checker = aoc2021::get_checker(day).ok_or_else(|| Error::input(format!("day {} is not solved", day)))?;
+    let input = util::read_input(path)?;
+
+    return match checker(&input) {
+        Ok(()) => {
+            println!("{}: no problems found", path);
+            Ok(())
+        },
+        Err(error) => {
+            let error = error.with_file(path);
+            let problems = error.problems();
+            for problem in &problems {
+                println!("{}", problem);
+            }
+
+            let summary = if problems.len() == 1 { String::from("1 problem found") } else { format!("{} problems found", problems.len()) };
+            Err(Error::input(summary).with_file(path))
+        },
+    };
+}
+
+fn run_sonar(options: &SonarOptions) -> Result<(), Error> {
+    let mut output = io::stdout();
+
+    if options.input == "-" {
+        stream::analyze(io::stdin().lock(), &options.window_sizes, options.every, &mut output)?;
+    } else {
+        let file = fs::File::open(&options.input).map_err(|source| Error::Io { path: options.input.to_string(), source })?;
+        stream::analyze(BufReader::new(file), &options.window_sizes, options.every, &mut output).map_err(|error| error.with_file(&options.input))?;
+    }
+
+    return Ok(());
+}
+
+fn run_stats(options: &StatsCommandOptions) -> Result<(), Error> {
+    let (input_name, input) = read_day_input(1, &options.input)?;
+    let depths = Day1::parse(&input).map_err(|error| error.with_file(&input_name))?;
+    let report = stats::depth_report(&depths, &options.stats).map_err(|error| error.with_file(&input_name))?;
+
+    match options.format {
+        Format::Csv => print!("{}", report.to_csv()),
+        _ => print!("{}", report.to_text()),
+    }
+
+    return Ok(());
+}
+
+fn run_course(options: &CourseOptions) -> Result<(), Error> {
+    let (input_name, input) = read_day_input(2, &options.input)?;
+    let commands = Day2::parse(&input).map_err(|error| error.with_file(&input_name))?;
+    let mut model = day2::get_model(&options.model).ok_or_else(|| Error::input(format!("unknown model '{}'", options.model)))?;
+    let trajectory = trajectory::record_trajectory(&commands, model.as_mut());
+
+    match options.format {
+        Format::Csv => print!("{}", trajectory.to_csv()),
+        Format::Json => println!("{}", trajectory.to_json(options.below)),
+        Format::Text => {
+            let position = trajectory.final_position();
+            println!("final position: forward {}, depth {}", position.forward, position.depth);
+
+            match trajectory.max_depth() {
+                Some((step, depth)) => println!("max depth: {} at step {}", depth, step),
+                None => println!("max depth: no commands"),
+            }
+
+            if let Some(depth) = options.below {
+                match trajectory.first_step_below(depth) {
+                    Some(step) => println!("first below {}: step {}", depth, step),
+                    None => println!("first below {}: never", depth),
+                }
+            }
+        },
+    }
+
+    return Ok(());
+}
+
+fn parse_arguments(args: &[String]) -> Result<Command, String> {
+    return match args.first().map(String::as_str) {
+        Some("run") => Ok(Command::Run(parse_run_arguments(&args[1..])?)),
+        Some("bench") => Ok(Command::Bench(parse_bench_arguments(&args[1..])?)),
+        Some("new") => Ok(Command::New(parse_new_arguments(&args[1..])?)),
+        Some("generate") => Ok(Command::Generate(parse_generate_arguments(&args[1..])?)),
+        Some("sonar") => Ok(Command::Sonar(parse_sonar_arguments(&args[1..])?)),
+        Some("stats") => Ok(Command::Stats(parse_stats_arguments(&args[1..])?)),
+        Some("course") => Ok(Command::Course(parse_course_arguments(&args[1..])?)),
+        Some("check") => match &args[1..] {
+            [day, path] => Ok(Command::Check(parse_day(day)?, path.to_string())),
+            _ => Err(String::from("check requires a day and an input path")),
+        },
+        Some(command) => Err(format!("unknown command '{}'", command)),
+        None => Err(String::from("missing command")),
+    };
+}
+
+fn parse_run_arguments(args: &[String]) -> Result<RunOptions, String> {
+    let mut args = args.iter();
+    let mut options = RunOptions { days: (1..=aoc2021::NUMBER_OF_DAYS).collect(), parts: vec![1, 2], input: None, format: Format::Text };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part requires a value")?;
+                options.parts = match part.as_str() {
+                    "1" => vec![1],
+                    "2" => vec![2],
+                    _ => return Err(format!("invalid part '{}'", part)),
+                };
+            },
+            "--input" | "-i" => {
+                let path = args.next().ok_or("--input requires a value")?;
+                options.input = Some(path.to_string());
+            },
+            "--format" | "-f" => {
+                let format = args.next().ok_or("--format requires a value")?;
+                options.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    _ => return Err(format!("invalid format '{}'", format)),
+                };
+            },
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+            _ => options.days = parse_days(arg)?,
+        }
+    }
+
+    if options.input.is_some() && options.days.len() != 1 {
+        return Err(String::from("--input can only be used with a single day"));
+    }
+
+    return Ok(options);
+}
+
+fn parse_bench_arguments(args: &[String]) -> Result<BenchOptions, String> {
+    let mut args = args.iter();
+    let mut options = BenchOptions { days: (1..=aoc2021::NUMBER_OF_DAYS).collect(), runs: 10, save: None, baseline: None, threshold: 10.0 };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" | "-n" => {
+                let runs = args.next().ok_or("--runs requires a value")?;
+                options.runs = runs.parse::<usize>().ok().filter(|runs| *runs > 0).ok_or(format!("invalid number of runs '{}'", runs))?;
+            },
+            "--save" => {
+                let path = args.next().ok_or("--save requires a value")?;
+                options.save = Some(path.to_string());
+            },
+            "--baseline" => {
+                let path = args.next().ok_or("--baseline requires a value")?;
+                options.baseline = Some(path.to_string());
+            },
+            "--threshold" => {
+                let threshold = args.next().ok_or("--threshold requires a value")?;
+                options.threshold = threshold.parse::<f64>().map_err(|_| format!("invalid threshold '{}'", threshold))?;
+            },
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+            _ => options.days = parse_days(arg)?,
+        }
+    }
+
+    return Ok(options);
+}
+
+// Unlike the other commands, new accepts days that are not solved yet
+fn parse_new_arguments(args: &[String]) -> Result<u32, String> {
+    return match args {
+        [day] => day.parse::<u32>().ok().filter(|day| *day > 0).ok_or(format!("invalid day '{}'", day)),
+        _ => Err(String::from("new requires exactly one day")),
+    };
+}
+
+fn parse_generate_arguments(args: &[String]) -> Result<GenerateOptions, String> {
+    let mut args = args.iter();
+    let mut day: Option<u32> = None;
+    let mut options = GenerateOptions { day: 0, size: None, seed: 0, output: None };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" | "-s" => {
+                let size = args.next().ok_or("--size requires a value")?;
+                options.size = Some(size.parse::<usize>().map_err(|_| format!("invalid size '{}'", size))?);
+            },
+            "--seed" => {
+                let seed = args.next().ok_or("--seed requires a value")?;
+                options.seed = seed.parse::<u64>().map_err(|_| format!("invalid seed '{}'", seed))?;
+            },
+            "--output" | "-o" => {
+                let path = args.next().ok_or("--output requires a value")?;
+                options.output = Some(path.to_string());
+            },
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+            _ if day.is_some() => return Err(String::from("generate requires exactly one day")),
+            _ => day = Some(parse_day(arg)?),
+        }
+    }
+
+    options.day = day.ok_or("generate requires exactly one day")?;
+    return Ok(options);
+}
+
+fn parse_sonar_arguments(args: &[String]) -> Result<SonarOptions, String> {
+    let mut args = args.iter();
+    let mut options = SonarOptions { window_sizes: vec![1, 3], every: 1000, input: String::from("-") };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--windows" | "-w" => {
+                let sizes = args.next().ok_or("--windows requires a value")?;
+                options.window_sizes = sizes.split(',').map(|size| size.trim().parse::<usize>().map_err(|_| format!("invalid window size '{}'", size))).collect::<Result<Vec<usize>, String>>()?;
+            },
+            "--every" => {
+                let every = args.next().ok_or("--every requires a value")?;
+                options.every = every.parse::<u64>().map_err(|_| format!("invalid number of measurements '{}'", every))?;
+            },
+            "--input" | "-i" => {
+                let path = args.next().ok_or("--input requires a value")?;
+                options.input = path.to_string();
+            },
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+    }
+
+    return Ok(options);
+}
+
+fn parse_stats_arguments(args: &[String]) -> Result<StatsCommandOptions, String> {
+    let mut args = args.iter();
+    let mut options = StatsCommandOptions { input: None, stats: stats::StatsOptions::default(), format: Format::Text };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                let path = args.next().ok_or("--input requires a value")?;
+                options.input = Some(path.to_string());
+            },
+            "--window" | "-w" => {
+                let window = args.next().ok_or("--window requires a value")?;
+                options.stats.average_window = window.parse::<usize>().ok().filter(|window| *window > 0).ok_or(format!("invalid window '{}'", window))?;
+            },
+            "--spike-window" => {
+                let window = args.next().ok_or("--spike-window requires a value")?;
+                options.stats.spike_window = window.parse::<usize>().ok().filter(|window| *window > 0).ok_or(format!("invalid spike window '{}'", window))?;
+            },
+            "--threshold" => {
+                let threshold = args.next().ok_or("--threshold requires a value")?;
+                options.stats.spike_threshold = threshold.parse::<f64>().map_err(|_| format!("invalid threshold '{}'", threshold))?;
+            },
+            "--format" | "-f" => {
+                let format = args.next().ok_or("--format requires a value")?;
+                options.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "csv" => Format::Csv,
+                    _ => return Err(format!("invalid format '{}'", format)),
+                };
+            },
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+    }
+
+    return Ok(options);
+}
+
+fn parse_course_arguments(args: &[String]) -> Result<CourseOptions, String> {
+    let mut args = args.iter();
+    let mut options = CourseOptions { input: None, model: String::from("aim"), below: None, format: Format::Text };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                let path = args.next().ok_or("--input requires a value")?;
+                options.input = Some(path.to_string());
+            },
+            "--model" | "-m" => {
+                let model = args.next().ok_or("--model requires a value")?;
+                if day2::get_model(model).is_none() {
+                    return Err(format!("invalid model '{}'", model));
+                }
+                options.model = model.to_string();
+            },
+            "--below" => {
+                let depth = args.next().ok_or("--below requires a value")?;
+                options.below = Some(depth.parse::<i32>().map_err(|_| format!("invalid depth '{}'", depth))?);
+            },
+            "--format" | "-f" => {
+                let format = args.next().ok_or("--format requires a value")?;
+                options.format = match format.as_str() {
+                    "text" => Format::Text,
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    _ => return Err(format!("invalid format '{}'", format)),
+                };
+            },
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+    }
+
+    return Ok(options);
+}
+
+fn parse_days(days: &str) -> Result<Vec<u32>, String> {
+    if days == "all" {
+        return Ok((1..=aoc2021::NUMBER_OF_DAYS).collect());
+    }
+
+    let mut selected_days: Vec<u32> = vec![];
+
+    for part in days.split(',') {
+        let (first, last) = match part.split_once('-') {
+            Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+            None => (parse_day(part)?, parse_day(part)?),
+        };
+
+        if first > last {
+            return Err(format!("invalid day range '{}'", part));
+        }
+
+        selected_days.extend(first..=last);
+    }
+
+    return Ok(selected_days);
+}
+
+fn parse_day(day: &str) -> Result<u32, String> {
+    let number = day.trim().parse::<u32>().map_err(|_| format!("invalid day '{}'", day))?;
+
+    if !(1..=aoc2021::NUMBER_OF_DAYS).contains(&number) {
+        return Err(format!("day {} is not solved (available: 1-{})", number, aoc2021::NUMBER_OF_DAYS));
+    }
+
+    return Ok(number);
+}
+
+#[test]
+fn test_parse_arguments_single_day_and_part() {
+    let args: Vec<String> = ["run", "14", "--part", "2", "--input", "day14.txt", "--format", "json"].iter().map(|x| x.to_string()).collect();
+    let command = parse_arguments(&args).unwrap();
+
+    assert_eq!(command, Command::Run(RunOptions { days: vec![14], parts: vec![2], input: Some(String::from("day14.txt")), format: Format::Json }));
+}
+
+#[test]
+fn test_parse_arguments_ranges() {
+    assert_eq!(parse_days("3-5,9").unwrap(), vec![3, 4, 5, 9]);
+    assert_eq!(parse_days("all").unwrap().len(), aoc2021::NUMBER_OF_DAYS as usize);
+    assert!(parse_days("5-3").is_err());
+    assert!(parse_days("0").is_err());
+}
+
+#[test]
+fn test_parse_arguments_input_requires_single_day() {
+    let args: Vec<String> = ["run", "1-3", "--input", "day1.txt"].iter().map(|x| x.to_string()).collect();
+
+    assert!(parse_arguments(&args).is_err());
+}
+
+#[test]
+fn test_parse_arguments_bench() {
+    let args: Vec<String> = ["bench", "15-17", "--runs", "5", "--baseline", "bench.txt"].iter().map(|x| x.to_string()).collect();
+    let command = parse_arguments(&args).unwrap();
+
+    assert_eq!(command, Command::Bench(BenchOptions { days: vec![15, 16, 17], runs: 5, save: None, baseline: Some(String::from("bench.txt")), threshold: 10.0 }));
+}
+
+#[test]
+fn test_parse_arguments_new() {
+    let args: Vec<String> = ["new", "26"].iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(parse_arguments(&args).unwrap(), Command::New(26));
+    assert!(parse_new_arguments(&[String::from("0")]).is_err());
+    assert!(parse_new_arguments(&[]).is_err());
+}
+
+#[test]
+fn test_parse_arguments_generate() {
+    let args: Vec<String> = ["generate", "15", "--size", "5000", "--seed", "7"].iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(parse_arguments(&args).unwrap(), Command::Generate(GenerateOptions { day: 15, size: Some(5000), seed: 7, output: None }));
+    assert!(parse_generate_arguments(&[]).is_err());
+    assert!(parse_generate_arguments(&[String::from("1"), String::from("2")]).is_err());
+}
+
+#[test]
+fn test_parse_arguments_check() {
+    let args: Vec<String> = ["check", "13", "paper.txt"].iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(parse_arguments(&args).unwrap(), Command::Check(13, String::from("paper.txt")));
+    assert!(parse_arguments(&args[..2]).is_err());
+    assert!(parse_arguments(&[String::from("check"), String::from("26"), String::from("-")]).is_err());
+}
+
+#[test]
+fn test_parse_arguments_sonar() {
+    let args: Vec<String> = ["sonar", "--windows", "1,3,10", "--every", "50"].iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(parse_arguments(&args).unwrap(), Command::Sonar(SonarOptions { window_sizes: vec![1, 3, 10], every: 50, input: String::from("-") }));
+    assert_eq!(parse_sonar_arguments(&[]).unwrap(), SonarOptions { window_sizes: vec![1, 3], every: 1000, input: String::from("-") });
+    assert!(parse_sonar_arguments(&[String::from("--windows"), String::from("1,x")]).is_err());
+    assert!(parse_sonar_arguments(&[String::from("14")]).is_err());
+}
+
+#[test]
+fn test_parse_arguments_stats() {
+    let args: Vec<String> = ["stats", "--window", "5", "--threshold", "12.5", "--format", "csv"].iter().map(|x| x.to_string()).collect();
+    let expected = stats::StatsOptions { average_window: 5, spike_window: 10, spike_threshold: 12.5 };
+
+    assert_eq!(parse_arguments(&args).unwrap(), Command::Stats(StatsCommandOptions { input: None, stats: expected, format: Format::Csv }));
+    assert!(parse_stats_arguments(&[String::from("--window"), String::from("0")]).is_err());
+    assert!(parse_stats_arguments(&[String::from("--format"), String::from("json")]).is_err());
+}
+
+#[test]
+fn test_parse_arguments_course() {
+    let args: Vec<String> = ["course", "--model", "direct", "--below", "500", "--format", "json"].iter().map(|x| x.to_string()).collect();
+
+    assert_eq!(parse_arguments(&args).unwrap(), Command::Course(CourseOptions { input: None, model: String::from("direct"), below: Some(500), format: Format::Json }));
+    assert_eq!(parse_course_arguments(&[]).unwrap(), CourseOptions { input: None, model: String::from("aim"), below: None, format: Format::Text });
+    assert!(parse_course_arguments(&[String::from("--model"), String::from("drift")]).is_err());
+}
+
+#[test]
+fn test_run_day_without_runner() {
+    let options = RunOptions { days: vec![99], parts: vec![1, 2], input: Some(String::from("-")), format: Format::Text };
+
+    assert_eq!(run_day(99, &options).unwrap_err().to_string(), "day 99 is not solved");
+    assert_eq!(run_check(99, "-").unwrap_err().to_string(), "day 99 is not solved");
+}
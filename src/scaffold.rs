@@ -0,0 +1,183 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::util;
+
+/// `aoc new` inserts the module of a new day above this line in `src/lib.rs`.
+pub const MODULES_MARKER: &str = "// aoc new: modules";
+
+/// `aoc new` inserts the runner of a new day above this line in `get_runner` of `src/lib.rs`.
+pub const RUNNERS_MARKER: &str = "// aoc new: runners";
+/// `aoc new` inserts the check of a new day above this line in `get_checker` of `src/lib.rs`.
+pub const CHECKERS_MARKER: &str = "// aoc new: checkers";
+
+const NUMBER_OF_DAYS_PREFIX: &str = "pub const NUMBER_OF_DAYS: u32 = ";
+
+/// Name of the example input that is created in the examples directory of the day.
+pub const EXAMPLE_INPUT_NAME: &str = "example";
+
+/// The source file of a new day: a solution that only counts the lines, and ignored tests for the example and the puzzle input.
+pub fn day_template(day: u32) -> String {
+    return format!("use crate::error::Error;
+use crate::solution::{{Answer, Solution}};
+use crate::util;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {{
+        return Ok(util::numbered_lines(input).iter().map(|(_, line)| line.to_string()).collect());
+    }}
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, Error> {{
+        return Ok(lines.len().into());
+    }}
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, Error> {{
+        return Ok(lines.len().into());
+    }}
+}}
+
+#[test]
+#[ignore = \"the example answer is not known yet\"]
+fn test_day{day}_part1_example() {{
+    let input = util::read_input_file(\"{examples}/day{day}/{example}.txt\").unwrap();
+    let lines = Day{day}::parse(&input).unwrap();
+    assert_eq!(Day{day}::part1(&lines).unwrap(), Answer::Number(0));
+}}
+
+#[test]
+#[ignore = \"the answer is not known yet\"]
+fn test_day{day}_part1_solution() {{
+    let input = util::read_input_file(\"day{day}/{input}.txt\").unwrap();
+    let lines = Day{day}::parse(&input).unwrap();
+    assert_eq!(Day{day}::part1(&lines).unwrap(), Answer::Number(0));
+}}
+
+#[test]
+#[ignore = \"the example answer is not known yet\"]
+fn test_day{day}_part2_example() {{
+    let input = util::read_input_file(\"{examples}/day{day}/{example}.txt\").unwrap();
+    let lines = Day{day}::parse(&input).unwrap();
+    assert_eq!(Day{day}::part2(&lines).unwrap(), Answer::Number(0));
+}}
+
+#[test]
+#[ignore = \"the answer is not known yet\"]
+fn test_day{day}_part2_solution() {{
+    let input = util::read_input_file(\"day{day}/{input}.txt\").unwrap();
+    let lines = Day{day}::parse(&input).unwrap();
+    assert_eq!(Day{day}::part2(&lines).unwrap(), Answer::Number(0));
+}}
+", day = day, examples = util::EXAMPLES_DIR_NAME, example = EXAMPLE_INPUT_NAME, input = util::DEFAULT_INPUT_NAME);
+}
+
+/// The single day binary, see `run_binary`.
+pub fn binary_template(day: u32) -> String {
+    return format!("use aoc2021::day{day}::Day{day};
+
+fn main() {{
+    aoc2021::run_binary::<Day{day}>(\"{input_file}\");
+}}
+", day = day, input_file = util::input_file_name(day, util::DEFAULT_INPUT_NAME));
+}
+
+/// An answers file that only explains the format until the answers of the input are known.
+pub fn answers_template(input: &str) -> String {
+    return format!("# Expected answers: <input name> <part1|part2|value name> <answer>
+# {input} part1 <answer>
+", input = input);
+}
+
+/// Inserts `line` above the marker line, with the same indentation as the marker.
+pub fn insert_before_marker(contents: &str, marker: &str, line: &str) -> Result<String, Error> {
+    let marker_line = contents.lines().find(|x| x.trim() == marker).ok_or_else(|| Error::input(format!("marker '{}' not found", marker)))?;
+    let indentation = &marker_line[..marker_line.len() - marker_line.trim_start().len()];
+
+    return Ok(contents.replacen(marker_line, &format!("{}{}\n{}", indentation, line, marker_line), 1));
+}
+
+/// Adds the module and the runner of the day to `src/lib.rs` and raises `NUMBER_OF_DAYS` if needed.
+pub fn register_day(lib: &str, day: u32) -> Result<String, Error> {
+    let number_of_days_line = lib.lines().find(|x| x.starts_with(NUMBER_OF_DAYS_PREFIX)).ok_or_else(|| Error::input("NUMBER_OF_DAYS not found"))?;
+    let number_of_days = number_of_days_line[NUMBER_OF_DAYS_PREFIX.len()..].trim_end_matches(';').parse::<u32>().map_err(|_| {
+        Error::input(format!("invalid NUMBER_OF_DAYS in '{}'", number_of_days_line))
+    })?;
+
+    // All days up to NUMBER_OF_DAYS must have a runner, so the days are added in order
+    if day != number_of_days + 1 {
+        return Err(Error::input(format!("day {} can't be added, the next day is {}", day, number_of_days + 1)));
+    }
+
+    let lib = lib.replacen(number_of_days_line, &format!("{}{};", NUMBER_OF_DAYS_PREFIX, day), 1);
+    let lib = insert_before_marker(&lib, MODULES_MARKER, &format!("pub mod day{};", day))?;
+    let lib = insert_before_marker(&lib, RUNNERS_MARKER, &format!("{} => run::<day{}::Day{}>,", day, day, day))?;
+    let lib = insert_before_marker(&lib, CHECKERS_MARKER, &format!("{} => day{}::Day{}::check,", day, day, day))?;
+
+    return Ok(lib);
+}
+
+/// Adds the `[[bin]]` target of the day to `Cargo.toml`.
+pub fn register_binary(manifest: &str, day: u32) -> String {
+    return format!("{}\n\n[[bin]]\nname = \"day{}\"\npath = \"src/bin/day{}.rs\"\n", manifest.trim_end(), day, day);
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+    return fs::read_to_string(path).map_err(|source| Error::Io { path: path.display().to_string(), source });
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), Error> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(|source| Error::Io { path: directory.display().to_string(), source })?;
+    }
+
+    return fs::write(path, contents).map_err(|source| Error::Io { path: path.display().to_string(), source });
+}
+
+/// Creates all files of a new day in the crate directory `root` and registers it, returns the created and changed files.
+/// Nothing is written if one of the new files already exists.
+pub fn create_day(root: &Path, day: u32) -> Result<Vec<PathBuf>, Error> {
+    let data_directory = root.join("data").join(format!("day{}", day));
+    let examples_directory = root.join("data").join(util::EXAMPLES_DIR_NAME).join(format!("day{}", day));
+    let new_files: Vec<(PathBuf, String)> = vec![
+        (root.join(format!("src/day{}.rs", day)), day_template(day)),
+        (root.join(format!("src/bin/day{}.rs", day)), binary_template(day)),
+        (data_directory.join(format!("{}.txt", util::DEFAULT_INPUT_NAME)), String::new()),
+        (data_directory.join(crate::answers::ANSWERS_FILE_NAME), answers_template(util::DEFAULT_INPUT_NAME)),
+        (examples_directory.join(format!("{}.txt", EXAMPLE_INPUT_NAME)), String::new()),
+        (examples_directory.join(crate::answers::ANSWERS_FILE_NAME), answers_template(EXAMPLE_INPUT_NAME)),
+    ];
+
+    if let Some((path, _)) = new_files.iter().find(|(path, _)| path.exists()) {
+        return Err(Error::input(format!("{} already exists", path.display())));
+    }
+
+    // Both registrations are prepared first, so a missing marker doesn't leave a half registered day behind
+    let lib_path = root.join("src/lib.rs");
+    let manifest_path = root.join("Cargo.toml");
+    let lib = register_day(&read_file(&lib_path)?, day).map_err(|error| error.with_file(&lib_path.display().to_string()))?;
+    let manifest = register_binary(&read_file(&manifest_path)?, day);
+
+    let mut changed_files: Vec<PathBuf> = vec![];
+    for (path, contents) in new_files.into_iter().chain([(lib_path, lib), (manifest_path, manifest)]) {
+        write_file(&path, &contents)?;
+        changed_files.push(path);
+    }
+
+    return Ok(changed_files);
+}
+
+#[test]
+fn test_scaffold_register_day() {
+    let lib = "pub mod day1;\n// aoc new: modules\n\npub const NUMBER_OF_DAYS: u32 = 1;\n\nmatch day {\n    1 => run::<day1::Day1>,\n    // aoc new: runners\n    _ => return None,\n}\n\nmatch day {\n    1 => day1::Day1::check,\n    // aoc new: checkers\n    _ => return None,\n}\n";
+    let registered = register_day(lib, 2).unwrap();
+
+    assert_eq!(registered, "pub mod day1;\npub mod day2;\n// aoc new: modules\n\npub const NUMBER_OF_DAYS: u32 = 2;\n\nmatch day {\n    1 => run::<day1::Day1>,\n    2 => run::<day2::Day2>,\n    // aoc new: runners\n    _ => return None,\n}\n\nmatch day {\n    1 => day1::Day1::check,\n    2 => day2::Day2::check,\n    // aoc new: checkers\n    _ => return None,\n}\n");
+    assert!(register_day("pub mod day1;\n", 2).is_err());
+    assert_eq!(register_day(lib, 3).unwrap_err().to_string(), "day 3 can't be added, the next day is 2");
+    assert!(register_day(lib, 1).is_err());
+    assert!(register_binary("[lib]\n", 2).ends_with("[lib]\n\n[[bin]]\nname = \"day2\"\npath = \"src/bin/day2.rs\"\n"));
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+use crate::error::Error;
+
+/// Common interface of all puzzle solutions.
+///
+/// `parse` turns the raw puzzle input into the day specific representation,
+/// which is then shared by both parts. Malformed input is reported as an error
+/// instead of panicking later on, the parts only fail for well-formed inputs
+/// that have no solution.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Answer, Error>;
+    fn part2(input: &Self::Input) -> Result<Answer, Error>;
+
+    /// Reports all structural problems of the input at once without solving it. By default the input is parsed,
+    /// days that already do expensive work while parsing only check the structure.
+    fn check(input: &str) -> Result<(), Error> {
+        return Self::parse(input).map(|_| ());
+    }
+
+    /// Named intermediate results (e.g. the gamma rate of day 3) that the examples are checked against.
+    /// They are only computed when they are asked for (see `solve_values`). Most days have none.
+    fn values(_input: &Self::Input) -> Vec<(String, Answer)> {
+        return vec![];
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        };
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(number: i32) -> Self {
+        return Answer::Number(number as i64);
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(number: i64) -> Self {
+        return Answer::Number(number);
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(number: u64) -> Self {
+        return Answer::Number(number as i64);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        return Answer::Number(number as i64);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        return Answer::Text(text);
+    }
+}
@@ -1,53 +1,92 @@
+use std::env;
 use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-#[allow(dead_code)]
-pub fn read_input_file(input_file: &str) -> String
+use crate::error::Error;
+
+mod grid;
+mod random;
+pub mod search;
+pub use grid::Grid;
+pub use random::Random;
+
+// Name of the environment variable that points to a directory with the puzzle inputs
+pub const DATA_DIR_VARIABLE: &str = "AOC_DATA_DIR";
+
+// Name of the directory in a data directory with the examples of the puzzle descriptions, e.g. "examples/day3/example.txt"
+pub const EXAMPLES_DIR_NAME: &str = "examples";
+
+// Name of the input that is used when no other input is selected
+pub const DEFAULT_INPUT_NAME: &str = "input";
+
+// Every day has its own directory with one or more named inputs, e.g. "day14/input.txt"
+pub fn input_file_name(day: u32, name: &str) -> String
 {
-    let mut directory = std::env::current_exe().unwrap();
-    directory.pop();
+    return format!("day{}/{}.txt", day, name);
+}
 
-    // Unit Tests are executed in the "deps" subdirectory:
-    // "target/debug/deps" for unit tests
-    // "target/debug" for standard execution
-    let directory_name = directory.file_name().unwrap();
-    let is_executed_from_unit_test: bool = directory_name == "deps";
-    if is_executed_from_unit_test
+// Reads the input from the given path, "-" reads it from stdin
+pub fn read_input(path: &str) -> Result<String, Error>
+{
+    if path == "-"
     {
-        directory.pop();
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents).map_err(|source| Error::Io { path: String::from("<stdin>"), source })?;
+        return Ok(contents);
     }
 
-    let file_path = directory.join(input_file);
-    let contents = fs::read_to_string(file_path).expect("Error while reading input file");
-    
-    return contents;
+    return fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_string(), source });
 }
 
-#[allow(dead_code)]
-pub fn split_string_by_string(contents: &String, split_by: &str) -> Vec<String>
+// Reads a puzzle input by its name (e.g. "day14/input.txt") from the first data directory that contains it
+pub fn read_input_file(input_file: &str) -> Result<String, Error>
 {
-    let mut lines: Vec<String> = contents.split(split_by).map(String::from).collect();
-    lines.retain(|x| !x.is_empty());
+    let data_dir = env::var(DATA_DIR_VARIABLE).ok();
+    let candidates = input_file_candidates(input_file, data_dir.as_deref());
 
-    return lines;
+    return match candidates.iter().find(|path| path.is_file())
+    {
+        Some(path) => read_input(&path.display().to_string()),
+        None => Err(Error::NotFound { name: input_file.to_string(), searched: candidates.iter().map(|path| path.display().to_string()).collect() }),
+    };
 }
 
-#[allow(dead_code)]
-pub fn split_ints_by_string(contents: &String, split_by: &str) -> Vec<i32>
+pub fn input_file_candidates(input_file: &str, data_dir: Option<&str>) -> Vec<PathBuf>
 {
-    let mut lines: Vec<i32> = contents.split(split_by).map(|x| x.trim().parse::<i32>().unwrap_or_else(|_y| -1)).collect();
-    lines.retain(|x| x >= &0);
+    return data_directories(data_dir).iter().map(|directory| directory.join(input_file)).collect();
+}
+
+// The lookup order is: $AOC_DATA_DIR, "data" in the current directory, "data" in the crate directory
+pub fn data_directories(data_dir: Option<&str>) -> Vec<PathBuf>
+{
+    let mut directories: Vec<PathBuf> = vec![];
+    if let Some(data_dir) = data_dir
+    {
+        directories.push(PathBuf::from(data_dir));
+    }
+    directories.push(PathBuf::from("data"));
+    directories.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("data"));
+
+    directories.dedup();
+    return directories;
+}
+
+pub fn split_string_by_string(contents: &str, split_by: &str) -> Vec<String>
+{
+    let mut lines: Vec<String> = contents.split(split_by).map(String::from).collect();
+    lines.retain(|x| !x.is_empty());
 
     return lines;
 }
 
-#[allow(dead_code)]
-pub fn split_generics_by_string<T>(contents: &String, split_by: &str) -> Vec<T> where T: FromStr
+pub fn split_generics_by_string<T>(contents: &str, split_by: &str) -> Vec<T> where T: FromStr
 {
     return contents.split(split_by).filter_map(|x| x.trim().parse::<T>().ok()).collect();
 }
 
-#[allow(dead_code)]
 pub fn parse_strings_as_ints(lines: &Vec<String>) -> Vec<i32>
 {
     let mut ints: Vec<i32> = vec![];
@@ -58,4 +97,157 @@ pub fn parse_strings_as_ints(lines: &Vec<String>) -> Vec<i32>
     }
 
     return ints;
-}
\ No newline at end of file
+}
+
+// Returns all non-empty lines together with their 1-based line number
+pub fn numbered_lines(contents: &str) -> Vec<(usize, &str)>
+{
+    return contents.lines().enumerate().map(|(i, line)| (i + 1, line.trim_end())).filter(|(_, line)| !line.is_empty()).collect();
+}
+
+// Groups the non-empty lines into blocks that are separated by blank lines
+pub fn numbered_blocks(contents: &str) -> Vec<Vec<(usize, &str)>>
+{
+    let mut blocks: Vec<Vec<(usize, &str)>> = vec![];
+    let mut block: Vec<(usize, &str)> = vec![];
+
+    for (i, line) in contents.lines().enumerate()
+    {
+        let line = line.trim_end();
+        if line.is_empty()
+        {
+            if !block.is_empty()
+            {
+                blocks.push(block);
+                block = vec![];
+            }
+            continue;
+        }
+
+        block.push((i + 1, line));
+    }
+
+    if !block.is_empty()
+    {
+        blocks.push(block);
+    }
+
+    return blocks;
+}
+
+// Splits the text and returns the trimmed, non-empty elements together with their 1-based column
+pub fn split_with_columns<'a>(text: &'a str, split_by: &str) -> Vec<(usize, &'a str)>
+{
+    let mut elements: Vec<(usize, &str)> = vec![];
+    let mut offset = 0;
+
+    for element in text.split(split_by)
+    {
+        let trimmed = element.trim_start();
+        let column = offset + element.len() - trimmed.len() + 1;
+        let trimmed = trimmed.trim_end();
+        if !trimmed.is_empty()
+        {
+            elements.push((column, trimmed));
+        }
+        offset += element.len() + split_by.len();
+    }
+
+    return elements;
+}
+
+// Collects all values, or all errors if there are any, so that every problem of an input is reported at once
+pub fn collect_results<T, I>(results: I) -> Result<Vec<T>, Error> where I: IntoIterator<Item = Result<T, Error>>
+{
+    let mut values: Vec<T> = vec![];
+    let mut errors: Vec<Error> = vec![];
+
+    for result in results
+    {
+        match result
+        {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if !errors.is_empty()
+    {
+        return Err(Error::combine(errors));
+    }
+
+    return Ok(values);
+}
+
+pub fn parse_value<T>(text: &str, line: usize, column: usize) -> Result<T, Error> where T: FromStr
+{
+    return text.trim().parse::<T>().map_err(|_| Error::parse(line, column, format!("invalid number '{}'", text.trim())));
+}
+
+// Parses every non-empty line as a single value
+pub fn parse_lines<T>(contents: &str) -> Result<Vec<T>, Error> where T: FromStr
+{
+    return collect_results(numbered_lines(contents).iter().map(|(line, text)| parse_value::<T>(text, *line, 1)));
+}
+
+// Parses a separated list of values that starts at the given line and column
+pub fn parse_separated<T>(text: &str, split_by: &str, line: usize, column: usize) -> Result<Vec<T>, Error> where T: FromStr
+{
+    return collect_results(split_with_columns(text, split_by).iter().map(|(offset, value)| parse_value::<T>(value, line, column + offset - 1)));
+}
+
+// Parses lines of single digits (e.g. "2199943210") into rows of numbers, all rows must have the same length
+pub fn parse_digit_lines(contents: &str) -> Result<Vec<Vec<i32>>, Error>
+{
+    let lines = numbered_lines(contents);
+    if lines.is_empty()
+    {
+        return Err(Error::input("the input is empty"));
+    }
+
+    let width = lines[0].1.chars().count();
+    return collect_results(lines.iter().map(|(line_number, line)| parse_row(line, *line_number, width, "digits", |c| c.to_digit(10).map(|digit| digit as i32))));
+}
+
+// Converts every character of a line that must have `width` characters, a wrong length is reported before the
+// characters that can't be converted
+pub fn parse_row<T, F>(line: &str, line_number: usize, width: usize, name: &str, convert: F) -> Result<Vec<T>, Error> where F: Fn(char) -> Option<T>
+{
+    let mut results: Vec<Result<T, Error>> = vec![];
+
+    let length = line.chars().count();
+    if length != width
+    {
+        results.push(Err(Error::parse(line_number, 1, format!("expected {} {} like the first line, found {}", width, name, length))));
+    }
+
+    for (i, character) in line.chars().enumerate()
+    {
+        results.push(convert(character).ok_or_else(|| Error::parse(line_number, i + 1, format!("unexpected character '{}'", character))));
+    }
+
+    return collect_results(results);
+}
+
+#[test]
+fn test_parse_digit_lines_reports_all_problems()
+{
+    let error = parse_digit_lines("123\n4x6\n78\n9y1").unwrap_err();
+    let problems: Vec<String> = error.problems().iter().map(|problem| problem.to_string()).collect();
+
+    assert_eq!(problems, vec![
+        "line 2, column 2: unexpected character 'x'",
+        "line 3, column 1: expected 3 digits like the first line, found 2",
+        "line 4, column 2: unexpected character 'y'",
+    ]);
+}
+
+#[test]
+fn test_input_file_candidates_order()
+{
+    let candidates = input_file_candidates("day1/input.txt", Some("/inputs"));
+
+    assert_eq!(candidates[0], PathBuf::from("/inputs/day1/input.txt"));
+    assert_eq!(candidates[1], PathBuf::from("data/day1/input.txt"));
+    assert_eq!(candidates[2], Path::new(env!("CARGO_MANIFEST_DIR")).join("data/day1/input.txt"));
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::Error;
+
+const NEIGHBOUR_OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOUR_OFFSETS_8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A rectangular grid with flat row-major storage, indexed by `(x, y)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        return Grid { width, height, cells: vec![value; width * height] };
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid by calling `f(x, y)` for every position in row-major order.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..width * height).map(|index| f(index % width, index / width)).collect();
+        return Grid { width, height, cells };
+    }
+
+    /// Builds a grid from rows of equal length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::input(format!("all rows of the grid must have {} columns, row {} has {}", width, y + 1, rows[y].len())));
+        }
+
+        return Ok(Grid { width, height, cells: rows.into_iter().flatten().collect() });
+    }
+
+    /// Parses numbered lines (see `util::numbered_lines`) into a grid, `convert` maps every character to a cell.
+    pub fn parse_chars<F: Fn(char) -> Option<T>>(lines: &[(usize, &str)], convert: F) -> Result<Grid<T>, Error> {
+        let width = lines.first().map_or(0, |(_, line)| line.chars().count());
+        let rows = super::collect_results(lines.iter().map(|(line_number, line)| super::parse_row(line, *line_number, width, "characters", &convert)))?;
+
+        return Grid::from_rows(rows);
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.cells.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.cells.is_empty();
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        return x < self.width && y < self.height;
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        return if self.contains(x, y) { Some(&self.cells[self.index_of(x, y)]) } else { None };
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if !self.contains(x, y) {
+            return None;
+        }
+
+        let index = self.index_of(x, y);
+        return Some(&mut self.cells[index]);
+    }
+
+    /// Returns the index of `(x, y)` in the flat storage.
+    pub fn index_of(&self, x: usize, y: usize) -> usize {
+        return y * self.width + x;
+    }
+
+    /// Returns the `(x, y)` position of an index in the flat storage.
+    pub fn position_of(&self, index: usize) -> (usize, usize) {
+        return (index % self.width, index / self.width);
+    }
+
+    pub fn cells(&self) -> &[T] {
+        return &self.cells;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        return self.cells.iter();
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        return self.cells.iter_mut();
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        return (0..self.cells.len()).map(move |index| (index % width, index / width));
+    }
+
+    /// The horizontally and vertically adjacent positions that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        return self.neighbours(x, y, &NEIGHBOUR_OFFSETS_4);
+    }
+
+    /// The horizontally, vertically and diagonally adjacent positions that are inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        return self.neighbours(x, y, &NEIGHBOUR_OFFSETS_8);
+    }
+
+    fn neighbours(&self, x: usize, y: usize, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        return offsets.iter().filter_map(move |(dx, dy)| {
+            let neighbour_x = x.checked_add_signed(*dx)?;
+            let neighbour_y = y.checked_add_signed(*dy)?;
+            return if neighbour_x < width && neighbour_y < height { Some((neighbour_x, neighbour_y)) } else { None };
+        });
+    }
+
+    /// Moves from `(x, y)` by the offset and wraps around the edges, as if the grid was a torus. Panics if the grid
+    /// is empty, there is no position to wrap around to.
+    pub fn wrapping_offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> (usize, usize) {
+        assert!(!self.is_empty(), "can't wrap around an empty grid");
+
+        let wrapped_x = (x as isize + dx).rem_euclid(self.width as isize) as usize;
+        let wrapped_y = (y as isize + dy).rem_euclid(self.height as isize) as usize;
+        return (wrapped_x, wrapped_y);
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        return Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() };
+    }
+}
+
+impl Grid<i32> {
+    /// Parses lines of single digits (e.g. "2199943210") into a grid.
+    pub fn parse_digits(input: &str) -> Result<Grid<i32>, Error> {
+        return Grid::from_rows(super::parse_digit_lines(input)?);
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "position ({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height);
+        return &self.cells[self.index_of(x, y)];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "position ({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height);
+        let index = self.index_of(x, y);
+        return &mut self.cells[index];
+    }
+}
+
+/// Prints every row on its own line, without separators between the cells of a row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[test]
+fn test_grid_neighbours() {
+    let grid = Grid::parse_digits("123\n456").unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8(1, 1).collect::<Vec<_>>(), vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]);
+    assert_eq!(grid.to_string(), "123\n456");
+    assert_eq!(grid.wrapping_offset(2, 1, 1, 1), (0, 0));
+    assert_eq!(grid.wrapping_offset(0, 0, -1, -3), (2, 1));
+}
+
+#[test]
+fn test_grid_out_of_bounds() {
+    let mut grid = Grid::parse_digits("123\n456").unwrap();
+
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get_mut(3, 0), None);
+    assert_eq!(grid.get_mut(usize::MAX, usize::MAX), None);
+    *grid.get_mut(2, 1).unwrap() = 7;
+    assert_eq!(grid.to_string(), "123\n457");
+}
@@ -0,0 +1,65 @@
+/// A small seeded pseudo random number generator (SplitMix64), the same seed always gives the same numbers.
+/// It is meant for generating test inputs, not for anything that needs good randomness.
+#[derive(Clone, Debug)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        return Random { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    /// A number in `0..bound`, the bound must not be 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "the bound of a random number must not be 0");
+        return (self.next_u64() % bound as u64) as usize;
+    }
+
+    /// A number in the inclusive range `min..=max`.
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "the range {}..={} is empty", min, max);
+        return min + (self.next_u64() % ((max - min) as u64 + 1)) as i64;
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let fraction = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        return fraction < probability;
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.below(items.len())];
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[test]
+fn test_random_is_seeded() {
+    let numbers: Vec<u64> = (0..5).map(|_| Random::new(42).next_u64()).collect();
+    assert!(numbers.iter().all(|number| *number == numbers[0]));
+
+    let mut random = Random::new(7);
+    assert!((0..1000).map(|_| random.range(-3, 3)).all(|number| (-3..=3).contains(&number)));
+
+    let mut items: Vec<usize> = (0..20).collect();
+    random.shuffle(&mut items);
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<usize>>());
+}
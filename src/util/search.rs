@@ -0,0 +1,106 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The cheapest path found by a search, `states` starts with the start state and ends with the target.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+struct Visit {
+    cost: u64,
+    predecessor: Option<usize>,
+    done: bool,
+}
+
+/// Dijkstra's algorithm over arbitrary hashable states, `neighbours` returns the next states with the cost to reach them.
+pub fn dijkstra<S, N, I, T>(start: S, neighbours: N, is_target: T) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    T: Fn(&S) -> bool,
+{
+    return a_star(start, neighbours, |_| 0, is_target);
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a target and never drop by more than the cost
+/// of a step (0 everywhere is Dijkstra).
+pub fn a_star<S, N, I, H, T>(start: S, mut neighbours: N, heuristic: H, is_target: T) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: Fn(&S) -> u64,
+    T: Fn(&S) -> bool,
+{
+    // States are numbered in the order they are found, the queue and the predecessors only store these numbers
+    let mut states: Vec<S> = vec![start.clone()];
+    let mut visits: Vec<Visit> = vec![Visit { cost: 0, predecessor: None, done: false }];
+    let mut indices: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut queue: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+
+    while let Some(Reverse((_, index))) = queue.pop() {
+        if visits[index].done {
+            continue;
+        }
+        visits[index].done = true;
+
+        if is_target(&states[index]) {
+            return Some(build_path(states, &visits, index));
+        }
+
+        let cost = visits[index].cost;
+        for (neighbour, step_cost) in neighbours(&states[index]) {
+            let new_cost = cost + step_cost;
+            let neighbour_index = match indices.get(&neighbour) {
+                Some(neighbour_index) => *neighbour_index,
+                None => {
+                    states.push(neighbour.clone());
+                    visits.push(Visit { cost: u64::MAX, predecessor: None, done: false });
+                    indices.insert(neighbour, states.len() - 1);
+                    states.len() - 1
+                },
+            };
+
+            let visit = &mut visits[neighbour_index];
+            if !visit.done && new_cost < visit.cost {
+                visit.cost = new_cost;
+                visit.predecessor = Some(index);
+                queue.push(Reverse((new_cost + heuristic(&states[neighbour_index]), neighbour_index)));
+            }
+        }
+    }
+
+    return None;
+}
+
+fn build_path<S: Clone>(states: Vec<S>, visits: &[Visit], target: usize) -> Path<S> {
+    let mut path: Vec<S> = vec![];
+    let mut index = Some(target);
+
+    while let Some(i) = index {
+        path.push(states[i].clone());
+        index = visits[i].predecessor;
+    }
+
+    path.reverse();
+    return Path { cost: visits[target].cost, states: path };
+}
+
+#[test]
+fn test_search_shortest_path() {
+    // Two ways from 0 to 3: 0 -> 1 -> 3 costs 5, 0 -> 2 -> 3 costs 4
+    let edges: HashMap<u32, Vec<(u32, u64)>> = HashMap::from([(0, vec![(1, 1), (2, 3)]), (1, vec![(3, 4)]), (2, vec![(3, 1)]), (3, vec![])]);
+
+    let path = dijkstra(0, |state| edges[state].clone(), |state| *state == 3).unwrap();
+    assert_eq!(path, Path { cost: 4, states: vec![0, 2, 3] });
+
+    let path = a_star(0, |state| edges[state].clone(), |state| if *state == 3 { 0 } else { 1 }, |state| *state == 3).unwrap();
+    assert_eq!(path.cost, 4);
+
+    assert_eq!(dijkstra(0, |state| edges[state].clone(), |state| *state == 4), None);
+}
@@ -0,0 +1,27 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use aoc2021::{answers, util};
+
+// Checks every input that has an entry in the answers file of its day, in all data directories
+#[test]
+fn test_all_inputs_match_their_expected_answers() {
+    let data_dir = env::var(util::DATA_DIR_VARIABLE).ok();
+    let mut data_directories: Vec<PathBuf> = util::data_directories(data_dir.as_deref()).iter().filter_map(|directory| fs::canonicalize(directory).ok()).collect();
+    data_directories.dedup();
+
+    let mut checked = 0;
+    let mut failures: Vec<String> = vec![];
+
+    for data_directory in &data_directories {
+        for day in 1..=aoc2021::NUMBER_OF_DAYS {
+            let (day_checked, day_failures) = answers::check_answers(data_directory, day).unwrap();
+            checked += day_checked;
+            failures.extend(day_failures);
+        }
+    }
+
+    assert!(failures.is_empty(), "{} of {} answers are wrong:\n{}", failures.len(), checked, failures.join("\n"));
+    assert!(checked > 0, "no expected answers found in {:?}", data_directories);
+}
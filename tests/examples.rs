@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use aoc2021::{answers, util};
+
+// Checks all examples in data/examples against the answers and values listed in their answers files
+#[test]
+fn test_all_examples_match_their_expected_answers() {
+    let examples_directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("data").join(util::EXAMPLES_DIR_NAME);
+
+    let mut checked = 0;
+    let mut failures: Vec<String> = vec![];
+
+    for day in 1..=aoc2021::NUMBER_OF_DAYS {
+        let (day_checked, day_failures) = answers::check_answers(&examples_directory, day).unwrap();
+        checked += day_checked;
+        failures.extend(day_failures);
+    }
+
+    assert!(failures.is_empty(), "{} of {} example answers are wrong:\n{}", failures.len(), checked, failures.join("\n"));
+    assert!(checked > 0, "no example answers found in {}", examples_directory.display());
+}